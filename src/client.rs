@@ -2,12 +2,14 @@ use std::iter::Extend;
 
 use futures_core::stream::Stream;
 use hyper::{
+    body::HttpBody,
     client::{Client, HttpConnector},
     Request,
 };
 use hyper_tls::HttpsConnector;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::model::*;
 
@@ -17,6 +19,10 @@ const SANDBOX_DOMAIN: &str = "https://sandbox.plaid.com";
 const DEVELOPMENT_DOMAIN: &str = "https://development.plaid.com";
 const PRODUCTION_DOMAIN: &str = "https://production.plaid.com";
 
+/// Maximum number of transactions accepted by a single `/transactions/enrich`
+/// request.
+const ENRICH_BATCH_SIZE: usize = 100;
+
 /// Error returned by client requests.
 #[derive(Error, Debug)]
 pub enum ClientError {
@@ -31,6 +37,9 @@ pub enum ClientError {
     /// Plaid successfully returned a response but returned with errors.
     #[error(transparent)]
     App(#[from] ErrorResponse),
+    /// Error writing a streamed response body to the caller's writer.
+    #[error("failed to write response body: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// Credentials required to make authenticated calls to the Plaid API.
@@ -44,13 +53,14 @@ pub struct Credentials {
 
 /// Environment controls the domain for the client, matches Plaid's sandbox,
 /// development, and production environments.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum Environment {
     /// Used to configure the client to request against a the domain in the string.
     /// Should be a fully qualified domain with protocol and scheme, for example
     /// http://localhost:3000.
     Custom(String),
     /// Plaid sandbox environment.
+    #[default]
     Sandbox,
     /// Plaid development environment.
     Development,
@@ -58,19 +68,13 @@ pub enum Environment {
     Production,
 }
 
-impl std::default::Default for Environment {
-    fn default() -> Self {
-        Environment::Sandbox
-    }
-}
-
-impl std::string::ToString for Environment {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Environment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Environment::Sandbox => SANDBOX_DOMAIN.into(),
-            Environment::Development => DEVELOPMENT_DOMAIN.into(),
-            Environment::Production => PRODUCTION_DOMAIN.into(),
-            Environment::Custom(s) => s.into(),
+            Environment::Sandbox => f.write_str(SANDBOX_DOMAIN),
+            Environment::Development => f.write_str(DEVELOPMENT_DOMAIN),
+            Environment::Production => f.write_str(PRODUCTION_DOMAIN),
+            Environment::Custom(s) => f.write_str(s),
         }
     }
 }
@@ -111,7 +115,7 @@ impl Builder {
         }
     }
 
-    /// Override the default HTTP client.
+    // Override the default HTTP client.
     // pub fn with_http_client(mut self, client: impl HttpClient) -> Self {
     //     self.http = Some(Box::new(client));
     //     self
@@ -146,13 +150,10 @@ impl Builder {
 }
 
 impl Plaid {
-    async fn request<E: crate::model::Endpoint>(
+    async fn send<E: crate::model::Endpoint>(
         &self,
         endpoint: &E,
-    ) -> Result<E::Response, ClientError>
-    where
-        for<'de> <E as crate::model::Endpoint>::Response: serde::Deserialize<'de>,
-    {
+    ) -> Result<hyper::Response<hyper::Body>, ClientError> {
         let req = Request::builder()
             .method("POST")
             .uri(format!("{}{}", &self.env.to_string(), endpoint.path()))
@@ -165,10 +166,7 @@ impl Plaid {
         let res = self.http.request(req).await?;
 
         match res.status() {
-            hyper::http::StatusCode::OK => {
-                let res_bytes = hyper::body::to_bytes(res.into_body()).await?;
-                Ok(serde_json::from_slice::<E::Response>(&res_bytes)?)
-            }
+            hyper::http::StatusCode::OK => Ok(res),
             _ => {
                 let res_bytes = hyper::body::to_bytes(res.into_body()).await?;
                 Err(ClientError::from(serde_json::from_slice::<ErrorResponse>(
@@ -178,6 +176,41 @@ impl Plaid {
         }
     }
 
+    async fn request<E: crate::model::Endpoint>(
+        &self,
+        endpoint: &E,
+    ) -> Result<E::Response, ClientError>
+    where
+        for<'de> <E as crate::model::Endpoint>::Response: serde::Deserialize<'de>,
+    {
+        let res = self.send(endpoint).await?;
+        let res_bytes = hyper::body::to_bytes(res.into_body()).await?;
+        Ok(serde_json::from_slice::<E::Response>(&res_bytes)?)
+    }
+
+    /// Writes the response body to `writer` as chunks arrive instead of
+    /// buffering the whole body, returns the number of bytes written.
+    async fn request_to_writer<E, W>(
+        &self,
+        endpoint: &E,
+        writer: &mut W,
+    ) -> Result<u64, ClientError>
+    where
+        E: crate::model::Endpoint,
+        W: AsyncWrite + Unpin,
+    {
+        let mut body = self.send(endpoint).await?.into_body();
+        let mut written = 0;
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+
+        Ok(written)
+    }
+
     /// Returns details for institutions that match the query parameters up to a
     /// maximum of ten institutions per query.
     ///
@@ -307,6 +340,57 @@ impl Plaid {
         Ok(())
     }
 
+    /// Summarizes whether an Item needs attention, e.g. because its login or
+    /// consent has expired or its updates are failing.
+    ///
+    /// https://plaid.com/docs/api/items/#itemget
+    pub async fn item_health<P: AsRef<str> + serde::Serialize>(
+        &self,
+        access_token: P,
+    ) -> Result<ItemHealth, ClientError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Ok(self
+            .request(&GetItemRequest { access_token })
+            .await?
+            .health(now))
+    }
+
+    /// Lists the third party applications the end user has shared data with.
+    ///
+    /// https://plaid.com/docs/api/items/#itemapplicationlist
+    pub async fn item_applications<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &ListItemApplicationsRequest<P>,
+    ) -> Result<Vec<ConnectedApplication>, ClientError> {
+        Ok(self.request(req).await?.applications)
+    }
+
+    /// Updates the data an application can access on an Item.
+    ///
+    /// https://plaid.com/docs/api/items/#itemapplicationscopesupdate
+    pub async fn update_item_application_scopes<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &UpdateItemApplicationScopesRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Stops sharing an Item's data with an application.
+    ///
+    /// https://plaid.com/docs/api/items/#itemapplicationunlink
+    pub async fn unlink_item_application<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &UnlinkItemApplicationRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
     /// Updates the webhook URL associated with an Item. Updates trigger a
     /// `WEBHOOK_UPDATE_ACKNOWLEDGED` event to the new webhook URL.
     ///
@@ -365,9 +449,22 @@ impl Plaid {
         self.request(req).await
     }
 
-    /// Triggers a Transactions `DEFAULT_UPDATE` webhook for a given Sandbox
-    /// Item. If the Item does not support Transactions, a
-    /// `SANDBOX_PRODUCT_NOT_ENABLED` error will result.
+    /// Compares user provided identity data (name, address, phone number and
+    /// email) against the identity data on file with the financial
+    /// institution, returning a match score per field.
+    ///
+    /// https://plaid.com/docs/api/products/identity/#identitymatch
+    pub async fn identity_match<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &IdentityMatchRequest<'_, P>,
+    ) -> Result<IdentityMatchResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Triggers a webhook for a given Sandbox Item, a Transactions
+    /// `DEFAULT_UPDATE` webhook unless another type is requested. If the Item
+    /// does not support the webhook's product, a `SANDBOX_PRODUCT_NOT_ENABLED`
+    /// error will result. ITEM `ERROR` webhooks are fired by `reset_login`.
     ///
     /// https://plaid.com/docs/api/sandbox/#sandboxitemfire_webhook
     pub async fn fire_webhook<P: AsRef<str> + serde::Serialize>(
@@ -377,6 +474,41 @@ impl Plaid {
         self.request(req).await
     }
 
+    /// Simulates selecting accounts during a sandbox OAuth flow, used to
+    /// complete Link for OAuth institutions without a browser.
+    ///
+    /// https://plaid.com/docs/api/sandbox/#sandboxoauthselect_accounts
+    pub async fn select_oauth_accounts<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &SelectOAuthAccountsRequest<'_, P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Creates a processor token for a new sandbox Item, skipping Link and
+    /// the public token exchange.
+    ///
+    /// https://plaid.com/docs/api/sandbox/#sandboxprocessor_tokencreate
+    pub async fn create_sandbox_processor_token<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreateSandboxProcessorTokenRequest<P>,
+    ) -> Result<String, ClientError> {
+        Ok(self.request(req).await?.processor_token)
+    }
+
+    /// Triggers an Income webhook for an Item's income verification in the
+    /// sandbox.
+    ///
+    /// https://plaid.com/docs/api/sandbox/#sandboxincomefire_webhook
+    pub async fn fire_income_webhook<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &FireIncomeWebhookRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
     /// Changes the verification status of an Item in the sandbox in order to
     /// simulate the Automated Micro-deposit flow.
     ///
@@ -399,168 +531,710 @@ impl Plaid {
         self.request(req).await
     }
 
-    /// Provides a JSON Web Key (JWK) that can be used to verify a JWT.
+    /// Creates a payment recipient for use with Payment Initiation (UK and
+    /// Europe). Recipients are identified by an IBAN, BACS numbers or both.
     ///
-    /// https://plaid.com/docs/api/webhooks/webhook-verification/#webhook_verification_keyget
-    pub async fn create_webhook_verification_key<P: AsRef<str> + serde::Serialize>(
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationrecipientcreate
+    pub async fn create_payment_recipient<P: AsRef<str> + serde::Serialize>(
         &self,
-        req: &GetWebhookVerificationKeyRequest<P>,
-    ) -> Result<GetWebhookVerificationKeyResponse, ClientError> {
+        req: &CreatePaymentRecipientRequest<'_, P>,
+    ) -> Result<CreatePaymentRecipientResponse, ClientError> {
         self.request(req).await
     }
 
-    /// Gets information about a `link_token`, can be useful for debugging.
+    /// Returns details about a payment recipient.
     ///
-    /// https://plaid.com/docs/api/tokens/#linktokenget
-    pub async fn link_token<P: AsRef<str> + serde::Serialize>(
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationrecipientget
+    pub async fn payment_recipient<P: AsRef<str> + serde::Serialize>(
         &self,
-        req: &GetLinkTokenRequest<P>,
-    ) -> Result<GetLinkTokenResponse, ClientError> {
+        req: &GetPaymentRecipientRequest<P>,
+    ) -> Result<PaymentRecipient, ClientError> {
+        Ok(self.request(req).await?.recipient)
+    }
+
+    /// Lists all payment recipients created by the client.
+    ///
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationrecipientlist
+    pub async fn payment_recipients(&self) -> Result<Vec<PaymentRecipient>, ClientError> {
+        Ok(self
+            .request(&ListPaymentRecipientsRequest {})
+            .await?
+            .recipients)
+    }
+
+    /// Creates a one-time or standing order payment to a recipient. The
+    /// returned `payment_id` is used to create a `link_token` that the end
+    /// user authorises the payment with.
+    ///
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationpaymentcreate
+    pub async fn create_payment<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreatePaymentRequest<P>,
+    ) -> Result<CreatePaymentResponse, ClientError> {
         self.request(req).await
     }
 
-    /// Rotate the `access_token` associated with an Item. Call returns a new
-    /// `access_token` and immediately invalidates the previous token.
+    /// Returns details about a payment.
     ///
-    /// https://plaid.com/docs/api/tokens/#itemaccess_tokeninvalidate
-    pub async fn invalidate_access_token<P: AsRef<str> + serde::Serialize>(
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationpaymentget
+    pub async fn payment<P: AsRef<str> + serde::Serialize>(
         &self,
-        req: &InvalidateAccessTokenRequest<P>,
-    ) -> Result<InvalidateAccessTokenResponse, ClientError> {
+        req: &GetPaymentRequest<P>,
+    ) -> Result<Payment, ClientError> {
+        Ok(self.request(req).await?.payment)
+    }
+
+    /// Lists payments created by the client, most recent first. Results are
+    /// paginated with `next_cursor`.
+    ///
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationpaymentlist
+    pub async fn payments<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &ListPaymentsRequest<P>,
+    ) -> Result<ListPaymentsResponse, ClientError> {
         self.request(req).await
     }
 
-    /// Get detailed information on categories returned by Plaid. This endpoint
-    /// does not require authentication.
+    /// Refunds a payment, in full or in part, from the client's virtual
+    /// account. Only settled payments can be reversed.
     ///
-    /// https://plaid.com/docs/api/products/#categoriesget
-    pub async fn categories(
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationpaymentreverse
+    pub async fn reverse_payment<P: AsRef<str> + serde::Serialize>(
         &self,
-        req: &GetCategoriesRequest,
-    ) -> Result<GetCategoriesResponse, ClientError> {
+        req: &ReversePaymentRequest<P>,
+    ) -> Result<ReversePaymentResponse, ClientError> {
         self.request(req).await
     }
 
-    /// Initiates on-demand extraction to fetch the newest transactions for an
-    /// Item.
+    /// Creates a payment consent that can be used to initiate payments on
+    /// behalf of the user without re-authorisation.
     ///
-    /// https://plaid.com/docs/api/products/#transactionsrefresh
-    pub async fn refresh_transactions<P: AsRef<str> + serde::Serialize>(
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationconsentcreate
+    pub async fn create_payment_consent<P: AsRef<str> + serde::Serialize>(
         &self,
-        req: &RefreshTransactionsRequest<P>,
+        req: &CreatePaymentConsentRequest<'_, P>,
+    ) -> Result<CreatePaymentConsentResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Returns details about a payment consent.
+    ///
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationconsentget
+    pub async fn payment_consent<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetPaymentConsentRequest<P>,
+    ) -> Result<PaymentConsent, ClientError> {
+        Ok(self.request(req).await?.consent)
+    }
+
+    /// Revokes a payment consent, no further payments can be made with it.
+    ///
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationconsentrevoke
+    pub async fn revoke_payment_consent<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &RevokePaymentConsentRequest<P>,
     ) -> Result<(), ClientError> {
         self.request(req).await?;
         Ok(())
     }
 
-    /// Returns user-authorized transaction data for credit, depository, and
-    /// some loan-type accounts. For transaction history from investment
-    /// accounts, use investment endpoints instead (production only). Results
-    /// are paginated based on request options and defaults to 100 entities per
-    /// page.
+    /// Initiates a payment using a previously authorised payment consent.
     ///
-    /// https://plaid.com/docs/api/products/#transactionsget
-    pub async fn transactions<P: AsRef<str> + serde::Serialize>(
+    /// https://plaid.com/docs/api/products/payment-initiation/#payment_initiationconsentpaymentexecute
+    pub async fn execute_payment_consent<P: AsRef<str> + serde::Serialize>(
         &self,
-        req: &GetTransactionsRequest<P>,
-    ) -> Result<GetTransactionsResponse, ClientError> {
+        req: &ExecutePaymentConsentRequest<P>,
+    ) -> Result<ExecutePaymentConsentResponse, ClientError> {
         self.request(req).await
     }
 
-    /// Returns the set of transactions for the given access token _after_ the
-    /// provided cursor. If no cursor is provided, returns transactions from the
-    /// beginning of time. Results are paginated with the default page size set
-    /// to 100 transactions and the maximum set to 500.
+    /// Creates a processor token that can be handed to a Plaid partner (e.g.
+    /// Dwolla) so they can access data for a single account of an Item.
     ///
-    /// https://plaid.com/docs/api/products/transactions/#transactionssync
-    pub async fn transactions_sync<P: AsRef<str> + serde::Serialize>(
+    /// https://plaid.com/docs/api/processors/#processortokencreate
+    pub async fn create_processor_token<P: AsRef<str> + serde::Serialize>(
         &self,
-        req: &SyncTransactionsRequest<P>,
-    ) -> Result<SyncTransactionsResponse, ClientError> {
+        req: &CreateProcessorTokenRequest<P>,
+    ) -> Result<CreateProcessorTokenResponse, ClientError> {
         self.request(req).await
     }
 
-    /// Returns a Stream of transactions that can be used to iterative fetch
-    /// pages from the transaction endpoint. Each call will return the number of
-    /// items configured in the original request. The transactions will begin
-    /// after the last transaction if a cursor is provided.
-    #[cfg(feature = "streams")]
-    pub fn transactions_sync_iter(
+    /// Creates a Stripe bank account token which can be used to create an
+    /// ACH payment source with Stripe.
+    ///
+    /// https://plaid.com/docs/api/processors/#processorstripebank_account_tokencreate
+    pub async fn create_stripe_bank_account_token<P: AsRef<str> + serde::Serialize>(
         &self,
-        req: SyncTransactionsRequest<String>,
-    ) -> impl Stream<Item = Result<Vec<TransactionStream>, ClientError>> + '_ {
-        async_stream::try_stream! {
-            let mut request = req.clone();
+        req: &CreateStripeBankAccountTokenRequest<P>,
+    ) -> Result<CreateStripeBankAccountTokenResponse, ClientError> {
+        self.request(req).await
+    }
 
-            loop {
-                let res = self.transactions_sync(&request).await?;
+    /// Restricts the products a processor token can be used to access.
+    ///
+    /// https://plaid.com/docs/api/processors/#processortokenpermissionsset
+    pub async fn set_processor_token_permissions<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &SetProcessorTokenPermissionsRequest<'_, P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
 
-                let mut txns = vec![];
-                txns.extend(res.added.into_iter().map(|txn| TransactionStream::Added(txn)));
-                txns.extend(res.modified.into_iter().map(|txn| TransactionStream::Modified(txn)));
-                txns.extend(res.removed.into_iter().map(|txn| TransactionStream::Removed(txn.transaction_id)));
+    /// Returns the products a processor token is allowed to access.
+    ///
+    /// https://plaid.com/docs/api/processors/#processortokenpermissionsget
+    pub async fn processor_token_permissions<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetProcessorTokenPermissionsRequest<P>,
+    ) -> Result<Vec<String>, ClientError> {
+        Ok(self.request(req).await?.products)
+    }
 
-                if res.has_more {
-                    request.cursor = Some(res.next_cursor);
-                    yield txns;
+    /// Returns the bank account and routing numbers of the account associated
+    /// with a processor token. Called by processors using their own
+    /// credentials.
+    ///
+    /// https://plaid.com/docs/api/processor-partners/#processorauthget
+    pub async fn processor_auth<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetProcessorAuthRequest<P>,
+    ) -> Result<GetProcessorAuthResponse, ClientError> {
+        self.request(req).await
+    }
 
-                    continue;
-                }
+    /// Returns the real-time balance of the account associated with a
+    /// processor token.
+    ///
+    /// https://plaid.com/docs/api/processor-partners/#processorbalanceget
+    pub async fn processor_balance<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetProcessorBalanceRequest<P>,
+    ) -> Result<Account, ClientError> {
+        Ok(self.request(req).await?.account)
+    }
 
-                txns.push(TransactionStream::Done(res.next_cursor));
+    /// Returns account holder information for the account associated with a
+    /// processor token.
+    ///
+    /// https://plaid.com/docs/api/processor-partners/#processoridentityget
+    pub async fn processor_identity<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetProcessorIdentityRequest<P>,
+    ) -> Result<GetProcessorIdentityResponse, ClientError> {
+        self.request(req).await
+    }
 
-                return yield txns;
-            }
-        }
+    /// Creates a user token that identifies an end user across products that
+    /// are scoped to a user rather than a single Item, such as income
+    /// verification. Passing the token to `create_link_token` links new Items
+    /// to the user.
+    ///
+    /// https://plaid.com/docs/api/users/#usercreate
+    pub async fn create_user<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreateUserRequest<'_, P>,
+    ) -> Result<CreateUserResponse, ClientError> {
+        self.request(req).await
     }
 
-    /// Returns a Stream of transactions that can be used to iterative fetch
-    /// pages from the transaction endpoint. Each call will return the number of
-    /// items configured in the original request.
+    /// Updates the identity of a user created with `create_user`.
     ///
-    /// ```ignore
-    /// use futures_util::pin_mut;
-    /// use futures_util::StreamExt;
+    /// https://plaid.com/docs/api/users/#userupdate
+    pub async fn update_user<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &UpdateUserRequest<'_, P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Removes a user along with all of its Items.
     ///
-    /// ...
+    /// https://plaid.com/docs/api/users/#userremove
+    pub async fn remove_user<P: AsRef<str> + serde::Serialize>(
+        &self,
+        user_token: P,
+    ) -> Result<(), ClientError> {
+        self.request(&RemoveUserRequest { user_token }).await?;
+        Ok(())
+    }
+
+    /// Returns the Items associated with a user.
     ///
-    ///   let req = GetTransactionsRequest {
-    ///       access_token: res.access_token.as_str(),
-    ///       start_date: "2019-09-01",
-    ///       end_date: "2021-09-05",
-    ///       options: Some(GetTransactionsOptions {
-    ///           // Number of items to return per page.
-    ///           count: Some(10),
-    ///           // Number of items from the start_date to offset results by.
-    ///           offset: Some(5),
-    ///           account_ids: None,
-    ///           include_original_description: None,
-    ///       }),
-    ///   };
-    ///   let iter = client.transactions_iter(req);
-    ///   pin_mut!(iter);
+    /// https://plaid.com/docs/api/users/#useritemsget
+    pub async fn user_items<P: AsRef<str> + serde::Serialize>(
+        &self,
+        user_token: P,
+    ) -> Result<Vec<Item>, ClientError> {
+        Ok(self
+            .request(&GetUserItemsRequest { user_token })
+            .await?
+            .items)
+    }
+
+    /// Returns the bank income reports for a user, summarising income
+    /// sources detected in the transactions of the user's linked accounts.
     ///
-    ///   while let Some(txn) = iter.next().await {
-    ///     println!("{:?}", txn);
-    ///   }
-    /// ```
-    #[cfg(feature = "streams")]
-    pub fn transactions_iter<'a, P: AsRef<str> + serde::Serialize + Clone + 'a>(
-        &'a self,
-        req: GetTransactionsRequest<P>,
-    ) -> impl Stream<Item = Result<Vec<Transaction>, ClientError>> + 'a {
-        async_stream::try_stream! {
-            let mut yielded = 0;
-            let mut total_xacts = None;
-            let mut request = req.clone();
-            let count = req.options.as_ref().unwrap().count.unwrap_or(100);
-            let mut offset = req.options.as_ref().unwrap().offset.unwrap_or(0);
+    /// https://plaid.com/docs/api/products/income/#creditbank_incomeget
+    pub async fn bank_income<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetBankIncomeRequest<P>,
+    ) -> Result<GetBankIncomeResponse, ClientError> {
+        self.request(req).await
+    }
 
-            while total_xacts.is_none() || total_xacts.unwrap() > yielded {
-                if let Some(ref mut opts) = &mut request.options {
-                    opts.count = Some(count);
-                    opts.offset = Some(offset);
-                } else {
+    /// Refreshes the most recent bank income report for a user. A
+    /// `BANK_INCOME_REFRESH_COMPLETE` webhook is fired once the report is
+    /// ready.
+    ///
+    /// https://plaid.com/docs/api/products/income/#creditbank_incomerefresh
+    pub async fn refresh_bank_income<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &RefreshBankIncomeRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Returns payroll income data, including pay stubs and W-2s, retrieved
+    /// from a user's payroll provider or uploaded documents.
+    ///
+    /// https://plaid.com/docs/api/products/income/#creditpayroll_incomeget
+    pub async fn payroll_income<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetPayrollIncomeRequest<P>,
+    ) -> Result<GetPayrollIncomeResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Returns the results of the Link sessions a user completed for income
+    /// verification.
+    ///
+    /// https://plaid.com/docs/api/products/income/#creditsessionsget
+    pub async fn credit_sessions<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetCreditSessionsRequest<P>,
+    ) -> Result<Vec<CreditSession>, ClientError> {
+        Ok(self.request(req).await?.sessions)
+    }
+
+    /// Creates a deposit switch for an Item linked with Plaid. The returned
+    /// `deposit_switch_id` is used to create a `deposit_switch_token` or
+    /// `link_token` the user completes the switch with.
+    ///
+    /// https://plaid.com/docs/deposit-switch/reference/#deposit_switchcreate
+    pub async fn create_deposit_switch<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreateDepositSwitchRequest<'_, P>,
+    ) -> Result<CreateDepositSwitchResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Creates a deposit switch for an account that was not linked with
+    /// Plaid.
+    ///
+    /// https://plaid.com/docs/deposit-switch/reference/#deposit_switchaltcreate
+    pub async fn create_deposit_switch_alt<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreateDepositSwitchAltRequest<'_, P>,
+    ) -> Result<CreateDepositSwitchResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Returns the state of a deposit switch.
+    ///
+    /// https://plaid.com/docs/deposit-switch/reference/#deposit_switchget
+    pub async fn deposit_switch<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetDepositSwitchRequest<P>,
+    ) -> Result<DepositSwitch, ClientError> {
+        Ok(self.request(req).await?.deposit_switch)
+    }
+
+    /// Creates a `deposit_switch_token` used to initialize Link for a
+    /// deposit switch.
+    ///
+    /// https://plaid.com/docs/deposit-switch/reference/#deposit_switchtokencreate
+    pub async fn create_deposit_switch_token<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreateDepositSwitchTokenRequest<P>,
+    ) -> Result<CreateDepositSwitchTokenResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Prepares an Item for Signal so that scores returned by
+    /// `signal_evaluate` include data gathered when the Item was linked.
+    /// Only needed for Items not initialized with the `signal` product.
+    ///
+    /// https://plaid.com/docs/api/products/signal/#signalprepare
+    pub async fn signal_prepare<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &SignalPrepareRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Returns risk scores estimating the likelihood of an ACH debit being
+    /// returned, to be evaluated before the debit is initiated. In Sandbox
+    /// scores are derived from the transaction amount.
+    ///
+    /// https://plaid.com/docs/api/products/signal/#signalevaluate
+    pub async fn signal_evaluate<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &SignalEvaluateRequest<P>,
+    ) -> Result<SignalEvaluateResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Reports whether an evaluated ACH transaction was initiated, improving
+    /// the accuracy of future scores.
+    ///
+    /// https://plaid.com/docs/api/products/signal/#signaldecisionreport
+    pub async fn signal_decision_report<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &SignalDecisionReportRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Reports an ACH return for a transaction previously evaluated with
+    /// `signal_evaluate`.
+    ///
+    /// https://plaid.com/docs/api/products/signal/#signalreturnreport
+    pub async fn signal_return_report<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &SignalReturnReportRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Creates an identity verification (KYC) session for a user using a
+    /// template configured in the Plaid dashboard.
+    ///
+    /// https://plaid.com/docs/api/products/identity-verification/#identity_verificationcreate
+    pub async fn create_identity_verification<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreateIdentityVerificationRequest<P>,
+    ) -> Result<IdentityVerification, ClientError> {
+        Ok(self.request(req).await?.identity_verification)
+    }
+
+    /// Returns the status and results of an identity verification.
+    ///
+    /// https://plaid.com/docs/api/products/identity-verification/#identity_verificationget
+    pub async fn identity_verification<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetIdentityVerificationRequest<P>,
+    ) -> Result<IdentityVerification, ClientError> {
+        Ok(self.request(req).await?.identity_verification)
+    }
+
+    /// Lists identity verifications for a user and template. Results are
+    /// paginated with `next_cursor`.
+    ///
+    /// https://plaid.com/docs/api/products/identity-verification/#identity_verificationlist
+    pub async fn identity_verifications<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &ListIdentityVerificationsRequest<P>,
+    ) -> Result<ListIdentityVerificationsResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Allows a user to retry an identity verification, the returned
+    /// verification is a new attempt linked to the previous one.
+    ///
+    /// https://plaid.com/docs/api/products/identity-verification/#identity_verificationretry
+    pub async fn retry_identity_verification<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &RetryIdentityVerificationRequest<P>,
+    ) -> Result<IdentityVerification, ClientError> {
+        Ok(self.request(req).await?.identity_verification)
+    }
+
+    /// Screens an individual against the watchlists of a Monitor program.
+    ///
+    /// https://plaid.com/docs/api/products/monitor/#watchlist_screeningindividualcreate
+    pub async fn create_watchlist_screening<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreateWatchlistScreeningRequest<P>,
+    ) -> Result<WatchlistScreening, ClientError> {
+        Ok(self.request(req).await?.watchlist_screening)
+    }
+
+    /// Returns an individual watchlist screening.
+    ///
+    /// https://plaid.com/docs/api/products/monitor/#watchlist_screeningindividualget
+    pub async fn watchlist_screening<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetWatchlistScreeningRequest<P>,
+    ) -> Result<WatchlistScreening, ClientError> {
+        Ok(self.request(req).await?.watchlist_screening)
+    }
+
+    /// Lists individual watchlist screenings for a program. Results are
+    /// paginated with `next_cursor`.
+    ///
+    /// https://plaid.com/docs/api/products/monitor/#watchlist_screeningindividuallist
+    pub async fn watchlist_screenings<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &ListWatchlistScreeningsRequest<P>,
+    ) -> Result<ListWatchlistScreeningsResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Creates a review for an individual watchlist screening, confirming or
+    /// dismissing its hits.
+    ///
+    /// https://plaid.com/docs/api/products/monitor/#watchlist_screeningindividualreviewcreate
+    pub async fn create_watchlist_screening_review<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &CreateWatchlistScreeningReviewRequest<'_, P>,
+    ) -> Result<WatchlistScreeningReviewResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Lists the statements available for each account of an Item.
+    ///
+    /// https://plaid.com/docs/api/products/statements/#statementslist
+    pub async fn statements<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &ListStatementsRequest<P>,
+    ) -> Result<ListStatementsResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Downloads a statement PDF into `writer`. The body is streamed so large
+    /// statements are never held in memory, returns the number of bytes
+    /// written.
+    ///
+    /// https://plaid.com/docs/api/products/statements/#statementsdownload
+    pub async fn download_statement<P, W>(
+        &self,
+        req: &DownloadStatementRequest<P>,
+        writer: &mut W,
+    ) -> Result<u64, ClientError>
+    where
+        P: AsRef<str> + serde::Serialize,
+        W: AsyncWrite + Unpin,
+    {
+        self.request_to_writer(req, writer).await
+    }
+
+    /// Requests that Plaid fetch statements for the given date range, a
+    /// STATEMENTS_REFRESH_COMPLETE webhook is sent once finished.
+    ///
+    /// https://plaid.com/docs/api/products/statements/#statementsrefresh
+    pub async fn refresh_statements<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &RefreshStatementsRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Provides a JSON Web Key (JWK) that can be used to verify a JWT.
+    ///
+    /// https://plaid.com/docs/api/webhooks/webhook-verification/#webhook_verification_keyget
+    pub async fn create_webhook_verification_key<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetWebhookVerificationKeyRequest<P>,
+    ) -> Result<GetWebhookVerificationKeyResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Gets information about a `link_token`, can be useful for debugging.
+    ///
+    /// https://plaid.com/docs/api/tokens/#linktokenget
+    pub async fn link_token<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetLinkTokenRequest<P>,
+    ) -> Result<GetLinkTokenResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Rotate the `access_token` associated with an Item. Call returns a new
+    /// `access_token` and immediately invalidates the previous token.
+    ///
+    /// https://plaid.com/docs/api/tokens/#itemaccess_tokeninvalidate
+    pub async fn invalidate_access_token<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &InvalidateAccessTokenRequest<P>,
+    ) -> Result<InvalidateAccessTokenResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Get detailed information on categories returned by Plaid. This endpoint
+    /// does not require authentication.
+    ///
+    /// https://plaid.com/docs/api/products/#categoriesget
+    pub async fn categories(
+        &self,
+        req: &GetCategoriesRequest,
+    ) -> Result<GetCategoriesResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Initiates on-demand extraction to fetch the newest transactions for an
+    /// Item.
+    ///
+    /// https://plaid.com/docs/api/products/#transactionsrefresh
+    pub async fn refresh_transactions<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &RefreshTransactionsRequest<P>,
+    ) -> Result<(), ClientError> {
+        self.request(req).await?;
+        Ok(())
+    }
+
+    /// Returns user-authorized transaction data for credit, depository, and
+    /// some loan-type accounts. For transaction history from investment
+    /// accounts, use investment endpoints instead (production only). Results
+    /// are paginated based on request options and defaults to 100 entities per
+    /// page.
+    ///
+    /// https://plaid.com/docs/api/products/#transactionsget
+    pub async fn transactions<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetTransactionsRequest<P>,
+    ) -> Result<GetTransactionsResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Returns the set of transactions for the given access token _after_ the
+    /// provided cursor. If no cursor is provided, returns transactions from the
+    /// beginning of time. Results are paginated with the default page size set
+    /// to 100 transactions and the maximum set to 500.
+    ///
+    /// https://plaid.com/docs/api/products/transactions/#transactionssync
+    pub async fn transactions_sync<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &SyncTransactionsRequest<P>,
+    ) -> Result<SyncTransactionsResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Returns the recurring inflow and outflow streams (e.g. subscriptions
+    /// and paychecks) detected in an Item's transactions. Transactions must
+    /// be ready, e.g. after a `SYNC_UPDATES_AVAILABLE` webhook.
+    ///
+    /// https://plaid.com/docs/api/products/transactions/#transactionsrecurringget
+    pub async fn recurring_transactions<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &GetRecurringTransactionsRequest<'_, P>,
+    ) -> Result<GetRecurringTransactionsResponse, ClientError> {
+        self.request(req).await
+    }
+
+    /// Enriches transactions from sources other than Plaid with merchant,
+    /// counterparty, location and category data. Batches larger than Plaid's
+    /// limit of 100 transactions are split across multiple requests and
+    /// results are returned in the order of the request.
+    ///
+    /// https://plaid.com/docs/api/products/enrich/#transactionsenrich
+    pub async fn enrich_transactions<P: AsRef<str> + serde::Serialize>(
+        &self,
+        req: &EnrichTransactionsRequest<'_, P>,
+    ) -> Result<Vec<EnrichedTransaction>, ClientError> {
+        let mut enriched = Vec::with_capacity(req.transactions.len());
+        for batch in req.transactions.chunks(ENRICH_BATCH_SIZE) {
+            let res = self
+                .request(&EnrichTransactionsRequest {
+                    account_type: req.account_type,
+                    transactions: batch,
+                    options: req.options,
+                })
+                .await?;
+            enriched.extend(res.enriched_transactions);
+        }
+
+        Ok(enriched)
+    }
+
+    /// Returns a Stream of transactions that can be used to iterative fetch
+    /// pages from the transaction endpoint. Each call will return the number of
+    /// items configured in the original request. The transactions will begin
+    /// after the last transaction if a cursor is provided.
+    #[cfg(feature = "streams")]
+    pub fn transactions_sync_iter(
+        &self,
+        req: SyncTransactionsRequest<String>,
+    ) -> impl Stream<Item = Result<Vec<TransactionStream>, ClientError>> + '_ {
+        async_stream::try_stream! {
+            let mut request = req.clone();
+
+            loop {
+                let res = self.transactions_sync(&request).await?;
+
+                let mut txns = vec![];
+                txns.extend(res.added.into_iter().map(TransactionStream::Added));
+                txns.extend(res.modified.into_iter().map(TransactionStream::Modified));
+                txns.extend(res.removed.into_iter().map(|txn| TransactionStream::Removed(txn.transaction_id)));
+
+                if res.has_more {
+                    request.cursor = Some(res.next_cursor);
+                    yield txns;
+
+                    continue;
+                }
+
+                txns.push(TransactionStream::Done(res.next_cursor));
+
+                return yield txns;
+            }
+        }
+    }
+
+    /// Returns a Stream of transactions that can be used to iterative fetch
+    /// pages from the transaction endpoint. Each call will return the number of
+    /// items configured in the original request.
+    ///
+    /// ```ignore
+    /// use futures_util::pin_mut;
+    /// use futures_util::StreamExt;
+    ///
+    /// ...
+    ///
+    ///   let req = GetTransactionsRequest {
+    ///       access_token: res.access_token.as_str(),
+    ///       start_date: "2019-09-01",
+    ///       end_date: "2021-09-05",
+    ///       options: Some(GetTransactionsOptions {
+    ///           // Number of items to return per page.
+    ///           count: Some(10),
+    ///           // Number of items from the start_date to offset results by.
+    ///           offset: Some(5),
+    ///           account_ids: None,
+    ///           include_original_description: None,
+    ///       }),
+    ///   };
+    ///   let iter = client.transactions_iter(req);
+    ///   pin_mut!(iter);
+    ///
+    ///   while let Some(txn) = iter.next().await {
+    ///     println!("{:?}", txn);
+    ///   }
+    /// ```
+    #[cfg(feature = "streams")]
+    pub fn transactions_iter<'a, P: AsRef<str> + serde::Serialize + Clone + 'a>(
+        &'a self,
+        req: GetTransactionsRequest<P>,
+    ) -> impl Stream<Item = Result<Vec<Transaction>, ClientError>> + 'a {
+        async_stream::try_stream! {
+            let mut yielded = 0;
+            let mut total_xacts = None;
+            let mut request = req.clone();
+            let count = req.options.as_ref().unwrap().count.unwrap_or(100);
+            let mut offset = req.options.as_ref().unwrap().offset.unwrap_or(0);
+
+            while total_xacts.is_none() || total_xacts.unwrap() > yielded {
+                if let Some(ref mut opts) = &mut request.options {
+                    opts.count = Some(count);
+                    opts.offset = Some(offset);
+                } else {
                     request.options = Some(GetTransactionsOptions{
                         count: Some(count),
                         offset: Some(offset),
@@ -580,6 +1254,102 @@ impl Plaid {
             }
         }
     }
+
+    /// Returns a Stream of identity verifications that iteratively fetches
+    /// pages from the list endpoint until all verifications for the user and
+    /// template are read. Pages begin after the cursor if one is provided.
+    #[cfg(feature = "streams")]
+    pub fn identity_verifications_iter(
+        &self,
+        req: ListIdentityVerificationsRequest<String>,
+    ) -> impl Stream<Item = Result<Vec<IdentityVerification>, ClientError>> + '_ {
+        async_stream::try_stream! {
+            let mut request = req.clone();
+
+            loop {
+                let res = self.identity_verifications(&request).await?;
+
+                match res.next_cursor {
+                    Some(cursor) => {
+                        request.cursor = Some(cursor);
+                        yield res.identity_verifications;
+                    }
+                    None => return yield res.identity_verifications,
+                }
+            }
+        }
+    }
+
+    /// Returns a Stream of individual watchlist screenings that iteratively
+    /// fetches pages from the list endpoint until all screenings matching the
+    /// request are read. Pages begin after the cursor if one is provided.
+    #[cfg(feature = "streams")]
+    pub fn watchlist_screenings_iter(
+        &self,
+        req: ListWatchlistScreeningsRequest<String>,
+    ) -> impl Stream<Item = Result<Vec<WatchlistScreening>, ClientError>> + '_ {
+        async_stream::try_stream! {
+            let mut request = req.clone();
+
+            loop {
+                let res = self.watchlist_screenings(&request).await?;
+
+                match res.next_cursor {
+                    Some(cursor) => {
+                        request.cursor = Some(cursor);
+                        yield res.watchlist_screenings;
+                    }
+                    None => return yield res.watchlist_screenings,
+                }
+            }
+        }
+    }
+
+    /// Returns a Stream that pages through `/institutions/get` until every
+    /// institution matching the request has been returned, starting at the
+    /// request's offset. Each item is one page of up to `count` institutions.
+    /// After the first page reveals the total, up to `concurrency` pages are
+    /// requested at once; pages are still yielded in order.
+    ///
+    /// ```ignore
+    /// let index: InstitutionIndex = client
+    ///     .institutions_iter(req, 4)
+    ///     .try_concat()
+    ///     .await?
+    ///     .into_iter()
+    ///     .collect();
+    /// ```
+    #[cfg(feature = "streams")]
+    pub fn institutions_iter<'a, P: AsRef<str> + serde::Serialize + Clone + 'a>(
+        &'a self,
+        req: InstitutionsGetRequest<'a, P>,
+        concurrency: usize,
+    ) -> impl Stream<Item = Result<Vec<Institution>, ClientError>> + 'a {
+        use futures_util::stream::StreamExt;
+
+        async_stream::try_stream! {
+            let first = self.request(&req).await?;
+            let total = first.total;
+            let count = req.count.max(1);
+            if first.institutions.is_empty() {
+                return;
+            }
+            yield first.institutions;
+
+            let offsets = (req.offset + count..total).step_by(count);
+            let pages = futures_util::stream::iter(offsets)
+                .map(|offset| {
+                    let mut page = req.clone();
+                    page.offset = offset;
+                    async move { self.request(&page).await }
+                })
+                .buffered(concurrency.max(1));
+
+            for await page in pages {
+                yield page?.institutions;
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -679,24 +1449,147 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn can_fetch_accounts_with_token() {
+    async fn can_fetch_accounts_with_token() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["assets", "auth", "balance"],
+                options: None,
+            })
+            .await
+            .unwrap();
+
+        let res = client.exchange_public_token(public_token).await.unwrap();
+        assert!(!res.access_token.is_empty());
+        let accounts = client.accounts(res.access_token).await.unwrap();
+
+        insta::assert_json_snapshot!(accounts, {
+            "[].account_id" => "[account_id]"
+        });
+    }
+
+    #[tokio::test]
+    async fn can_create_custom_sandbox_user() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let password = SandboxUserConfig::new()
+            .with_account(
+                SandboxAccount::new(AccountType::Depository, "checking", 1234.5)
+                    .with_meta(SandboxAccountMeta {
+                        name: Some("Fixture Checking".into()),
+                        ..SandboxAccountMeta::default()
+                    })
+                    .with_transaction(SandboxTransaction::new(
+                        "2022-01-03",
+                        42.0,
+                        "Fixture Coffee",
+                    )),
+            )
+            .to_password();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["auth"],
+                options: Some(CreatePublicTokenOptions {
+                    webhook: None,
+                    override_username: Some(SANDBOX_CUSTOM_USERNAME),
+                    override_password: Some(password.as_str()),
+                    transactions: None,
+                    income_verification: None,
+                }),
+            })
+            .await
+            .unwrap();
+
+        let res = client.exchange_public_token(public_token).await.unwrap();
+        let accounts = client.accounts(res.access_token).await.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].name, "Fixture Checking");
+    }
+
+    #[tokio::test]
+    async fn can_set_verification_status() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: "ins_109508",
+                initial_products: &["auth"],
+                options: Some(CreatePublicTokenOptions {
+                    webhook: None,
+                    override_username: Some("user_good"),
+                    override_password: Some("microdeposits_good"),
+                    transactions: None,
+                    income_verification: None,
+                }),
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+        let accounts = client.accounts(&res.access_token).await.unwrap();
+
+        let res = client
+            .set_verification_status(&SetVerificationStatusRequest {
+                access_token: res.access_token.as_str(),
+                account_id: accounts[0].account_id.as_str(),
+                verification_status: "automatically_verified",
+            })
+            .await
+            .unwrap();
+        assert!(!res.request_id.is_empty());
+    }
+
+    #[tokio::test]
+    async fn select_oauth_accounts_rejects_unknown_state() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let res = client
+            .select_oauth_accounts(&SelectOAuthAccountsRequest {
+                oauth_state_id: "unknown-oauth-state-id",
+                accounts: &[],
+            })
+            .await;
+
+        // A state ID is only issued by Link's OAuth flow, check the request
+        // reaches Plaid and its error is parsed.
+        assert!(matches!(res, Err(ClientError::App(_))));
+    }
+
+    #[tokio::test]
+    async fn can_create_sandbox_processor_token() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let processor_token = client
+            .create_sandbox_processor_token(&CreateSandboxProcessorTokenRequest {
+                institution_id: INSTITUTION_ID,
+                options: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(processor_token.starts_with("processor-sandbox"));
+    }
+
+    #[tokio::test]
+    async fn can_create_sandbox_pub_token_with_history() {
         let client = Builder::new().with_credentials(credentials()).build();
         let public_token = client
             .create_public_token(CreatePublicTokenRequest {
                 institution_id: INSTITUTION_ID,
-                initial_products: &["assets", "auth", "balance"],
-                options: None,
+                initial_products: &["transactions"],
+                options: Some(CreatePublicTokenOptions {
+                    webhook: None,
+                    override_username: None,
+                    override_password: None,
+                    transactions: Some(CreatePublicTokenOptionsTransactions {
+                        start_date: None,
+                        end_date: None,
+                        days_requested: Some(90),
+                    }),
+                    income_verification: None,
+                }),
             })
             .await
             .unwrap();
 
-        let res = client.exchange_public_token(public_token).await.unwrap();
-        assert!(!res.access_token.is_empty());
-        let accounts = client.accounts(res.access_token).await.unwrap();
-
-        insta::assert_json_snapshot!(accounts, {
-            "[].account_id" => "[account_id]"
-        });
+        assert!(!public_token.is_empty());
     }
 
     #[tokio::test]
@@ -718,6 +1611,24 @@ mod tests {
         insta::assert_json_snapshot!(item, {
             ".item_id" => "[item_id]"
         });
+        assert_eq!(
+            client.item_health(&res.access_token).await.unwrap(),
+            ItemHealth::Healthy
+        );
+        let applications = client
+            .item_applications(&ListItemApplicationsRequest {
+                access_token: Some(&res.access_token),
+            })
+            .await
+            .unwrap();
+        assert!(applications.is_empty());
+
+        client.reset_login(&res.access_token).await.unwrap();
+        assert!(client
+            .item_health(&res.access_token)
+            .await
+            .unwrap()
+            .needs_update_mode());
 
         // Should succeed.
         client.item_del(res.access_token).await.unwrap();
@@ -747,6 +1658,10 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(create_res.link_token, res.link_token);
+        let metadata = res.metadata.unwrap();
+        assert_eq!(metadata.client_name.as_deref(), Some("test_client"));
+        assert_eq!(metadata.initial_products, vec!["transactions"]);
+        assert!(res.link_sessions.is_empty());
     }
 
     #[tokio::test]
@@ -968,6 +1883,10 @@ mod tests {
             })
             .await
             .unwrap();
+        for account in &res.accounts {
+            assert!(!account.owners.is_empty());
+            assert!(!account.owners[0].names.is_empty());
+        }
         insta::assert_json_snapshot!(res, {
             ".accounts[].account_id" => "[account_id]",
             ".item.item_id" => "[item_id]",
@@ -975,6 +1894,36 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    async fn can_match_identity() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["auth", "identity"],
+                options: None,
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+
+        let res = client
+            .identity_match(&IdentityMatchRequest {
+                access_token: res.access_token.as_str(),
+                user: Some(IdentityMatchUser {
+                    legal_name: Some("Alberta Bobbeth Charleson"),
+                    ..IdentityMatchUser::default()
+                }),
+                options: None,
+            })
+            .await
+            .unwrap();
+        assert!(res
+            .accounts
+            .iter()
+            .all(|account| account.legal_name.is_some()));
+    }
+
     #[tokio::test]
     async fn can_invalidate_access_token() {
         let client = Builder::new().with_credentials(credentials()).build();
@@ -1011,6 +1960,7 @@ mod tests {
                     override_username: None,
                     override_password: None,
                     transactions: None,
+                    income_verification: None,
                 }),
             })
             .await
@@ -1019,6 +1969,7 @@ mod tests {
         let res = client
             .fire_webhook(&FireWebhookRequest {
                 access_token: res.access_token.as_str(),
+                webhook_type: None,
                 webhook_code: WebhookCode::DefaultUpdate,
             })
             .await
@@ -1026,4 +1977,450 @@ mod tests {
 
         assert!(res.webhook_fired);
     }
+
+    #[tokio::test]
+    async fn can_fire_item_webhooks() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["transactions"],
+                options: Some(CreatePublicTokenOptions {
+                    webhook: Some("localhost:3000"),
+                    override_username: None,
+                    override_password: None,
+                    transactions: None,
+                    income_verification: None,
+                }),
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+        let access_token = res.access_token.as_str();
+
+        for (webhook_type, webhook_code) in [
+            (WebhookType::Transactions, WebhookCode::SyncUpdatesAvailable),
+            (WebhookType::Item, WebhookCode::NewAccountsAvailable),
+            (WebhookType::Item, WebhookCode::PendingDisconnect),
+        ] {
+            let res = client
+                .fire_webhook(&FireWebhookRequest {
+                    access_token,
+                    webhook_type: Some(webhook_type),
+                    webhook_code,
+                })
+                .await
+                .unwrap();
+            assert!(res.webhook_fired);
+        }
+
+        client.reset_login(access_token).await.unwrap();
+        let res = client
+            .fire_webhook(&FireWebhookRequest {
+                access_token,
+                webhook_type: Some(WebhookType::Item),
+                webhook_code: WebhookCode::LoginRepaired,
+            })
+            .await
+            .unwrap();
+        assert!(res.webhook_fired);
+    }
+
+    #[tokio::test]
+    async fn can_create_payment_recipient() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let res = client
+            .create_payment_recipient(&CreatePaymentRecipientRequest {
+                name: "John Doe",
+                iban: Some("GB33BUKB20201555555555"),
+                bacs: None,
+                address: Some(PaymentInitiationAddress {
+                    street: &["96 Guild Street", "9th Floor"],
+                    city: "London",
+                    postal_code: "SE14 8JW",
+                    country: "GB",
+                }),
+            })
+            .await
+            .unwrap();
+
+        let recipient = client
+            .payment_recipient(&GetPaymentRecipientRequest {
+                recipient_id: &res.recipient_id,
+            })
+            .await
+            .unwrap();
+        assert_eq!(recipient.recipient_id, res.recipient_id);
+        assert_eq!(recipient.iban.as_deref(), Some("GB33BUKB20201555555555"));
+    }
+
+    #[tokio::test]
+    async fn can_create_processor_token() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["auth"],
+                options: None,
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+        let accounts = client.accounts(&res.access_token).await.unwrap();
+
+        let res = client
+            .create_processor_token(&CreateProcessorTokenRequest {
+                access_token: res.access_token.as_str(),
+                account_id: accounts[0].account_id.as_str(),
+                processor: "dwolla",
+            })
+            .await
+            .unwrap();
+        assert!(!res.processor_token.is_empty());
+    }
+
+    #[tokio::test]
+    async fn can_create_user() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let client_user_id = format!(
+            "test-user-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis()
+        );
+        let res = client
+            .create_user(&CreateUserRequest {
+                client_user_id: client_user_id.as_str(),
+                consumer_report_user_identity: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(!res.user_token.is_empty());
+        let sessions = client
+            .credit_sessions(&GetCreditSessionsRequest {
+                user_token: &res.user_token,
+            })
+            .await
+            .unwrap();
+        assert!(sessions.is_empty());
+        assert!(client.user_items(&res.user_token).await.unwrap().is_empty());
+
+        client
+            .update_user(&UpdateUserRequest {
+                user_token: res.user_token.as_str(),
+                consumer_report_user_identity: ConsumerReportUserIdentity {
+                    first_name: "Carmen",
+                    last_name: "Berzatto",
+                    phone_numbers: &["+13125551212"],
+                    emails: &["carmen@example.com"],
+                    ssn_last_4: None,
+                    date_of_birth: "1987-01-31",
+                    primary_address: ConsumerReportUserAddress {
+                        city: "Chicago",
+                        region: "IL",
+                        street: "3200 W Armitage Ave",
+                        postal_code: "60657",
+                        country: "US",
+                    },
+                },
+            })
+            .await
+            .unwrap();
+        client.remove_user(&res.user_token).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn can_create_deposit_switch() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["auth"],
+                options: None,
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+        let accounts = client.accounts(&res.access_token).await.unwrap();
+
+        let res = client
+            .create_deposit_switch(&CreateDepositSwitchRequest {
+                target_access_token: res.access_token.as_str(),
+                target_account_id: accounts[0].account_id.as_str(),
+                country_code: None,
+                options: None,
+            })
+            .await
+            .unwrap();
+
+        let deposit_switch = client
+            .deposit_switch(&GetDepositSwitchRequest {
+                deposit_switch_id: &res.deposit_switch_id,
+            })
+            .await
+            .unwrap();
+        assert_eq!(deposit_switch.state, DepositSwitchState::Initialized);
+    }
+
+    #[tokio::test]
+    async fn can_evaluate_signal() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["auth"],
+                options: None,
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+        let auth = client
+            .auth(&GetAuthRequest {
+                access_token: res.access_token.as_str(),
+                options: None,
+            })
+            .await
+            .unwrap();
+
+        client
+            .signal_prepare(&SignalPrepareRequest {
+                access_token: res.access_token.as_str(),
+            })
+            .await
+            .unwrap();
+        let evaluation = client
+            .signal_evaluate(&SignalEvaluateRequest {
+                access_token: res.access_token.as_str(),
+                account_id: auth.numbers.ach[0].account_id.as_str(),
+                client_transaction_id: "txn-1234",
+                #[cfg(not(feature = "decimal"))]
+                amount: 102.05,
+                #[cfg(feature = "decimal")]
+                amount: crate::Decimal::new(10205, 2),
+                user_present: None,
+                client_user_id: None,
+                is_recurring: None,
+                default_payment_method: None,
+                user: None,
+                device: None,
+            })
+            .await
+            .unwrap();
+        assert!(evaluation.scores.customer_initiated_return_risk.is_some());
+
+        client
+            .signal_decision_report(&SignalDecisionReportRequest {
+                client_transaction_id: "txn-1234",
+                initiated: true,
+                days_funds_on_hold: None,
+                decision_outcome: Some(SignalDecisionOutcome::Approve),
+                payment_method: Some(SignalPaymentMethod::StandardAch),
+                amount_instantly_available: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn can_read_recurring_transactions() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["transactions"],
+                options: None,
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+
+        // Streams are only available once transactions are ready.
+        let iter = client.transactions_sync_iter(SyncTransactionsRequest {
+            access_token: res.access_token.clone(),
+            ..Default::default()
+        });
+        futures_lite::pin!(iter);
+        while let Some(txns) = iter.next().await {
+            txns.unwrap();
+        }
+
+        let recurring = client
+            .recurring_transactions(&GetRecurringTransactionsRequest {
+                access_token: res.access_token.as_str(),
+                account_ids: None,
+            })
+            .await
+            .unwrap();
+        assert!(!recurring.updated_datetime.is_empty());
+    }
+
+    #[tokio::test]
+    async fn can_enrich_transactions() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        // Larger than a single request to exercise batching.
+        let transactions = (0..150)
+            .map(|id| ClientProvidedTransaction {
+                id: format!("txn-{}", id),
+                description: "PURCHASE WM SUPERCENTER #1700".to_string(),
+                #[cfg(not(feature = "decimal"))]
+                amount: 72.1,
+                #[cfg(feature = "decimal")]
+                amount: crate::Decimal::new(7210, 2),
+                direction: EnrichTransactionDirection::Outflow,
+                iso_currency_code: "USD".to_string(),
+                location: None,
+                mcc: None,
+                date_posted: None,
+            })
+            .collect::<Vec<_>>();
+
+        let enriched = client
+            .enrich_transactions(&EnrichTransactionsRequest {
+                account_type: AccountType::Depository,
+                transactions: &transactions,
+                options: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(enriched.len(), transactions.len());
+        assert_eq!(enriched[120].id, "txn-120");
+    }
+
+    #[tokio::test]
+    async fn can_download_statement() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["statements"],
+                options: None,
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+        let statements = client
+            .statements(&ListStatementsRequest {
+                access_token: res.access_token.as_str(),
+            })
+            .await
+            .unwrap();
+        let statement = statements
+            .accounts
+            .iter()
+            .flat_map(|account| account.statements.iter())
+            .next()
+            .expect("sandbox item should have statements");
+
+        let mut pdf = Vec::new();
+        let written = client
+            .download_statement(
+                &DownloadStatementRequest {
+                    access_token: res.access_token.as_str(),
+                    statement_id: statement.statement_id.as_str(),
+                },
+                &mut pdf,
+            )
+            .await
+            .unwrap();
+        assert_eq!(written as usize, pdf.len());
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[tokio::test]
+    async fn can_fetch_institution_status() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let res = client
+            .get_institution_by_id(&InstitutionGetRequest {
+                institution_id: INSTITUTION_ID,
+                country_codes: &["US"],
+                options: Some(GetInstitutionFilter {
+                    include_optional_metadata: None,
+                    include_status: Some(true),
+                    include_auth_metadata: Some(true),
+                    include_payment_initiation_metadata: None,
+                }),
+            })
+            .await
+            .unwrap();
+
+        let status = res.status.expect("status should be included");
+        assert!(status.item_logins.is_some());
+        assert!(res.auth_metadata.is_some());
+    }
+
+    #[tokio::test]
+    async fn can_index_institutions() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let pages = client.institutions_iter(
+            InstitutionsGetRequest {
+                count: 100,
+                offset: 0,
+                country_codes: &["US"],
+                options: None,
+            },
+            2,
+        );
+        let institutions: Vec<Institution> = pages
+            .take(3)
+            .try_collect::<_, _, Vec<_>>()
+            .await
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect();
+        assert_eq!(institutions.len(), 300);
+
+        let index: InstitutionIndex = institutions.into_iter().collect();
+        let institution = index.iter().next().unwrap();
+        assert_eq!(
+            index.get(&institution.institution_id).unwrap().name,
+            institution.name
+        );
+        assert!(index
+            .search(&institution.name.to_uppercase())
+            .iter()
+            .any(|i| i.institution_id == institution.institution_id));
+    }
+
+    #[tokio::test]
+    async fn can_create_update_mode_link_token() {
+        let client = Builder::new().with_credentials(credentials()).build();
+        let public_token = client
+            .create_public_token(CreatePublicTokenRequest {
+                institution_id: INSTITUTION_ID,
+                initial_products: &["transactions"],
+                options: None,
+            })
+            .await
+            .unwrap();
+        let res = client.exchange_public_token(public_token).await.unwrap();
+
+        assert_eq!(
+            CreateLinkTokenRequest::builder(
+                "test_client",
+                "en",
+                &["US"],
+                LinkUser::new("test-user")
+            )
+            .with_account_selection_enabled(true)
+            .build()
+            .unwrap_err(),
+            LinkTokenError::MissingProducts
+        );
+
+        let req = CreateLinkTokenRequest::builder(
+            "test_client",
+            "en",
+            &["US"],
+            LinkUser::new("test-user"),
+        )
+        .with_access_token(res.access_token.as_str())
+        .with_account_selection_enabled(true)
+        .build()
+        .unwrap();
+        let res = client.create_link_token(&req).await.unwrap();
+        assert!(!res.link_token.is_empty());
+    }
 }
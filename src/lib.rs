@@ -15,19 +15,19 @@ __These bindings are not official Plaid bindings.__
 
 # Glossary
 * Item - A Item represents a connection to a single financial instution.
-         Typically links are associated with a pair of credentials and an
-         `access_token`. Items are associated to one or more accounts.
+  Typically links are associated with a pair of credentials and an
+  `access_token`. Items are associated to one or more accounts.
 
 * Link - Link is a client-side component that Plaid provides to link to accounts.
-         See https://plaid.com/docs/link/#introduction-to-link for more
-         information.
+  See https://plaid.com/docs/link/#introduction-to-link for more
+  information.
 
 * Account - An account is a financial account that is linked to an Item. An item,
-            or financial institution, may have multiple accounts for a single
-            user (e.g. a checking account and a credit account).
+  or financial institution, may have multiple accounts for a single
+  user (e.g. a checking account and a credit account).
 
 * Product - Entities with services offered by Plaid, see
-            https://plaid.com/docs/api/products/ for more information.
+  https://plaid.com/docs/api/products/ for more information.
 
 # Features
 * Idiomatic futures generator for easily reading multiple pages of transactions.
@@ -49,6 +49,15 @@ Plaid's APIs.
   When enabled currency amounts in response payloads will be parsed as
   `rust_decimal::Decimal` types for more correct use in computations.
 
+* **webhooks** -
+  When enabled this adds the `webhook` module, which verifies that incoming
+  webhooks were sent by Plaid and caches Plaid's verification keys.
+
+* **webhook-server** -
+  When enabled this adds `webhook::WebhookService`, a hyper service that
+  verifies incoming Plaid webhooks and dispatches them to registered handlers.
+  Enables **webhooks**.
+
 # Limitations
 Some endpoints are production specific or beta products and are not yet
 supported by the client.
@@ -61,6 +70,10 @@ https://docs.google.com/spreadsheets/d/1xqUXdfllo37Rx5MVrQODbVqNQvuktiCVL5Uh8y9m
 pub mod client;
 /// Data types for entities returned by Plaid API endpoints.
 pub mod model;
+#[cfg(feature = "webhooks")]
+#[deny(missing_docs)]
+/// Verifies the authenticity of webhooks sent by Plaid.
+pub mod webhook;
 
 /// Re-exports Decimal type used for currency amounts.
 #[cfg(feature = "decimal")]
@@ -10,7 +10,7 @@ pub(crate) trait Endpoint: serde::Serialize {
     }
 }
 
-#[derive(thiserror::Error, Debug, Deserialize, Serialize, Eq, PartialEq, Default)]
+#[derive(thiserror::Error, Debug, Deserialize, Serialize, Eq, PartialEq, Default, Clone)]
 #[error("request failed with code {error_code:?}: {display_message:?}")]
 pub struct ErrorResponse {
     pub display_message: Option<String>,
@@ -23,7 +23,7 @@ pub struct ErrorResponse {
     pub suggested_action: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ErrorType {
     InvalidRequest,
@@ -0,0 +1,197 @@
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct CreateDepositSwitchRequest<'a, T: AsRef<str>> {
+    /// Access token for the Item the deposit should be switched to.
+    pub target_access_token: T,
+    /// Account ID of the account deposits should be switched to.
+    pub target_account_id: T,
+    /// ISO 3166-1 alpha-2 country code, one of US or CA.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<DepositSwitchCreateOptions<'a, T>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepositSwitchCreateOptions<'a, T: AsRef<str>> {
+    /// URL to receive the `SWITCH_STATE_UPDATE` webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<T>,
+    /// Access tokens for Items the user has linked, used to pre-fill the
+    /// user's income accounts in Link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_item_access_tokens: Option<&'a [T]>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateDepositSwitchRequest<'_, T> {
+    type Response = CreateDepositSwitchResponse;
+
+    fn path(&self) -> String {
+        "/deposit_switch/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateDepositSwitchResponse {
+    pub deposit_switch_id: String,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateDepositSwitchAltRequest<'a, T: AsRef<str>> {
+    /// The account deposits should be switched to, for accounts not linked
+    /// with Plaid.
+    pub target_account: DepositSwitchTargetAccount<T>,
+    pub target_user: DepositSwitchTargetUser<T>,
+    /// ISO 3166-1 alpha-2 country code, one of US or CA.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<DepositSwitchCreateOptions<'a, T>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepositSwitchTargetAccount<T: AsRef<str>> {
+    pub account_number: T,
+    pub routing_number: T,
+    /// The name of the account, displayed to the user in Link.
+    pub account_name: T,
+    pub account_subtype: DepositSwitchAccountSubtype,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositSwitchAccountSubtype {
+    Checking,
+    Savings,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepositSwitchTargetUser<T: AsRef<str>> {
+    pub given_name: T,
+    pub family_name: T,
+    /// Phone number in E.164 format.
+    pub phone: T,
+    pub email: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<DepositSwitchAddress<T>>,
+    /// The taxpayer ID of the user, typically the SSN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_payer_id: Option<T>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepositSwitchAddress<T: AsRef<str>> {
+    pub street: T,
+    pub city: T,
+    pub region: T,
+    pub postal_code: T,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateDepositSwitchAltRequest<'_, T> {
+    type Response = CreateDepositSwitchResponse;
+
+    fn path(&self) -> String {
+        "/deposit_switch/alt/create".into()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetDepositSwitchRequest<T: AsRef<str>> {
+    pub deposit_switch_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetDepositSwitchRequest<T> {
+    type Response = GetDepositSwitchResponse;
+
+    fn path(&self) -> String {
+        "/deposit_switch/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetDepositSwitchResponse {
+    #[serde(flatten)]
+    pub deposit_switch: DepositSwitch,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DepositSwitch {
+    pub deposit_switch_id: String,
+    pub target_account_id: Option<String>,
+    pub target_item_id: Option<String>,
+    pub state: DepositSwitchState,
+    /// The method used to make the switch, none until the switch is
+    /// completed.
+    pub switch_method: Option<DepositSwitchMethod>,
+    /// When true, the user's payroll has multiple allocations.
+    pub account_has_multiple_allocations: Option<bool>,
+    /// When true, the target account receives the remainder of the user's
+    /// paycheck after other allocations.
+    pub is_allocated_remainder: Option<bool>,
+    pub percent_allocated: Option<f64>,
+    #[cfg(not(feature = "decimal"))]
+    pub amount_allocated: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub amount_allocated: Option<Decimal>,
+    pub employer_name: Option<String>,
+    /// The ID of the employer selected by the user, matches the
+    /// `employer_id` returned by `/employers/search`.
+    pub employer_id: Option<String>,
+    pub institution_name: Option<String>,
+    pub institution_id: Option<String>,
+    /// Date the deposit switch was created (YYYY-MM-DD).
+    pub date_created: String,
+    /// Date the deposit switch was completed (YYYY-MM-DD).
+    pub date_completed: Option<String>,
+    pub email_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositSwitchState {
+    /// The deposit switch has been created but the user has not completed
+    /// Link.
+    Initialized,
+    /// The user completed Link and the switch is being processed by the
+    /// payroll provider.
+    Processing,
+    Completed,
+    Error,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositSwitchMethod {
+    Instant,
+    Mail,
+    Pdf,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateDepositSwitchTokenRequest<T: AsRef<str>> {
+    pub deposit_switch_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateDepositSwitchTokenRequest<T> {
+    type Response = CreateDepositSwitchTokenResponse;
+
+    fn path(&self) -> String {
+        "/deposit_switch/token/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateDepositSwitchTokenResponse {
+    pub deposit_switch_token: String,
+    /// Expiration time of the token, in ISO 8601 format.
+    pub deposit_switch_token_expiration_time: String,
+    pub request_id: String,
+}
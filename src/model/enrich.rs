@@ -0,0 +1,129 @@
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct EnrichTransactionsRequest<'a, T: AsRef<str>> {
+    /// The type of account the transactions belong to, one of depository or
+    /// credit.
+    pub account_type: AccountType,
+    /// Transactions to enrich. Plaid accepts up to 100 transactions per
+    /// request, `Plaid::enrich_transactions` splits larger batches.
+    pub transactions: &'a [ClientProvidedTransaction<T>],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<EnrichTransactionsOptions>,
+}
+
+#[derive(Debug, Serialize, Copy, Clone, Default)]
+pub struct EnrichTransactionsOptions {
+    /// Include the legacy `category` and `category_id` fields in results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_legacy_category: Option<bool>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for EnrichTransactionsRequest<'_, T> {
+    type Response = EnrichTransactionsResponse;
+
+    fn path(&self) -> String {
+        "/transactions/enrich".into()
+    }
+}
+
+/// A transaction from a source other than Plaid.
+#[derive(Debug, Serialize, Clone)]
+pub struct ClientProvidedTransaction<T: AsRef<str>> {
+    /// A unique ID for the transaction, used to match enriched results.
+    pub id: T,
+    /// The raw description of the transaction.
+    pub description: T,
+    /// The absolute value of the transaction amount.
+    #[cfg(not(feature = "decimal"))]
+    pub amount: f64,
+    #[cfg(feature = "decimal")]
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    pub direction: EnrichTransactionDirection,
+    pub iso_currency_code: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<TransactionLocation>,
+    /// Merchant category code for the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mcc: Option<T>,
+    /// Date the transaction posted, in ISO 8601 format (YYYY-MM-DD).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_posted: Option<T>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EnrichTransactionDirection {
+    /// Money moving into the account, e.g. a refund or deposit.
+    Inflow,
+    /// Money moving out of the account, e.g. a purchase.
+    Outflow,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EnrichTransactionsResponse {
+    pub enriched_transactions: Vec<EnrichedTransaction>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EnrichedTransaction {
+    pub id: String,
+    pub description: String,
+    #[cfg(not(feature = "decimal"))]
+    pub amount: f64,
+    #[cfg(feature = "decimal")]
+    pub amount: Decimal,
+    pub direction: Option<EnrichTransactionDirection>,
+    pub iso_currency_code: String,
+    pub enrichments: Enrichments,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Enrichments {
+    #[serde(default)]
+    pub counterparties: Vec<Counterparty>,
+    /// A unique ID identifying the merchant, stable across transactions.
+    pub entity_id: Option<String>,
+    pub legacy_category: Option<Vec<String>>,
+    pub legacy_category_id: Option<String>,
+    pub location: TransactionLocation,
+    pub logo_url: Option<String>,
+    pub merchant_name: Option<String>,
+    /// One of online, in store or other.
+    pub payment_channel: String,
+    pub phone_number: Option<String>,
+    pub personal_finance_category: Option<PersonalFinanceCategory>,
+    pub personal_finance_category_icon_url: Option<String>,
+    pub website: Option<String>,
+    pub check_number: Option<String>,
+}
+
+/// A party involved in a transaction, such as the merchant or a payment
+/// processor.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Counterparty {
+    pub name: String,
+    pub r#type: CounterpartyType,
+    pub website: Option<String>,
+    pub logo_url: Option<String>,
+    /// A unique ID identifying the counterparty, stable across transactions.
+    pub entity_id: Option<String>,
+    pub confidence_level: Option<ConfidenceLevel>,
+    pub phone_number: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CounterpartyType {
+    Merchant,
+    FinancialInstitution,
+    PaymentApp,
+    Marketplace,
+    PaymentTerminal,
+    IncomeSource,
+}
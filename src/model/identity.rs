@@ -21,7 +21,183 @@ impl<T: AsRef<str> + serde::Serialize> Endpoint for GetIdentityRequest<'_, T> {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetIdentityResponse {
-    pub accounts: Vec<Account>,
+    pub accounts: Vec<AccountIdentity>,
     pub item: Item,
     pub request_id: String,
 }
+
+/// An account along with the identity information of its owners, as returned
+/// by the identity endpoints.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountIdentity {
+    #[serde(flatten)]
+    pub account: Account,
+    /// Data returned by the financial institution about the account owner or
+    /// owners. Joint accounts may return multiple owners.
+    pub owners: Vec<Owner>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Owner {
+    /// Names associated with the account owner, may include nicknames or
+    /// business names.
+    pub names: Vec<String>,
+    pub phone_numbers: Vec<PhoneNumber>,
+    pub emails: Vec<Email>,
+    pub addresses: Vec<OwnerAddress>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PhoneNumber {
+    /// The phone number.
+    pub data: String,
+    /// When true, identifies the phone number as the primary number on an
+    /// account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<PhoneNumberType>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PhoneNumberType {
+    Home,
+    Work,
+    Office,
+    Mobile,
+    Mobile1,
+    Other,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Email {
+    /// The email address.
+    pub data: String,
+    /// When true, identifies the email address as the primary email on an
+    /// account.
+    pub primary: bool,
+    pub r#type: EmailType,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailType {
+    Primary,
+    Secondary,
+    Other,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OwnerAddress {
+    pub data: AddressData,
+    /// When true, identifies the address as the primary address on an
+    /// account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AddressData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// The region or state, e.g. "NC".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    pub street: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    /// ISO 3166-1 alpha-2 country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IdentityMatchRequest<'a, T: AsRef<str>> {
+    pub access_token: T,
+    /// User provided identity data to compare against the data on file with
+    /// the financial institution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<IdentityMatchUser<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<IdentityFilter<'a, T>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct IdentityMatchUser<T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_name: Option<T>,
+    /// Phone number in E.164 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<IdentityMatchAddress<T>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct IdentityMatchAddress<T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<T>,
+    /// ISO 3166-1 alpha-2 country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for IdentityMatchRequest<'_, T> {
+    type Response = IdentityMatchResponse;
+
+    fn path(&self) -> String {
+        "/identity/match".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IdentityMatchResponse {
+    pub accounts: Vec<AccountIdentityMatchScore>,
+    pub item: Item,
+    pub request_id: String,
+}
+
+/// An account along with scores describing how closely the user provided
+/// identity data matches the data on file. Scores range from 0 to 100 and are
+/// only present for fields that were provided in the request.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountIdentityMatchScore {
+    #[serde(flatten)]
+    pub account: Account,
+    pub legal_name: Option<NameMatchScore>,
+    pub phone_number: Option<MatchScore>,
+    pub email_address: Option<MatchScore>,
+    pub address: Option<AddressMatchScore>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct MatchScore {
+    pub score: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct NameMatchScore {
+    pub score: Option<u8>,
+    /// True if the first name or last name matched exactly.
+    pub is_first_name_or_last_name_match: Option<bool>,
+    /// True if the provided name matched a known nickname of the name on file.
+    pub is_nickname_match: Option<bool>,
+    /// True if the name on file appears to be a business name.
+    pub is_business_name_detected: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct AddressMatchScore {
+    pub score: Option<u8>,
+    /// True if the postal code matched the postal code on file.
+    pub is_postal_code_match: Option<bool>,
+}
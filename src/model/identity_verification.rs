@@ -0,0 +1,349 @@
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct CreateIdentityVerificationRequest<T: AsRef<str>> {
+    /// ID of the template configured in the Plaid dashboard.
+    pub template_id: T,
+    /// When true, a `shareable_url` is returned that can be sent to the user
+    /// to complete verification outside of Link.
+    pub is_shareable: bool,
+    /// Whether the user has consented to the collection and processing of
+    /// their data.
+    pub gave_consent: bool,
+    pub user: IdentityVerificationRequestUser<T>,
+    /// When true, returns the existing verification for the same user and
+    /// template instead of failing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_idempotent: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct IdentityVerificationRequestUser<T: AsRef<str>> {
+    pub client_user_id: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<T>,
+    /// Phone number in E.164 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<T>,
+    /// Date of birth in the format YYYY-MM-DD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<IdentityVerificationName<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<IdentityVerificationAddress<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number: Option<IdentityVerificationIdNumber<T>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct IdentityVerificationName<T: AsRef<str>> {
+    pub given_name: T,
+    pub family_name: T,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct IdentityVerificationAddress<T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street2: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<T>,
+    /// An ISO 3166-2 subdivision code, e.g. "CA".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<T>,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: T,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct IdentityVerificationIdNumber<T: AsRef<str>> {
+    pub value: T,
+    /// The type of ID number, e.g. us_ssn or gb_nino.
+    pub r#type: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateIdentityVerificationRequest<T> {
+    type Response = IdentityVerificationResponse;
+
+    fn path(&self) -> String {
+        "/identity_verification/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IdentityVerificationResponse {
+    #[serde(flatten)]
+    pub identity_verification: IdentityVerification,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IdentityVerification {
+    pub id: String,
+    pub client_user_id: String,
+    /// Creation timestamp, in ISO 8601 format.
+    pub created_at: String,
+    /// Completion timestamp, in ISO 8601 format.
+    pub completed_at: Option<String>,
+    /// The ID of the verification this attempt retried, if any.
+    pub previous_attempt_id: Option<String>,
+    pub shareable_url: Option<String>,
+    pub template: IdentityVerificationTemplate,
+    pub user: IdentityVerificationUser,
+    pub status: IdentityVerificationStatus,
+    pub steps: IdentityVerificationSteps,
+    pub documentary_verification: Option<DocumentaryVerification>,
+    pub kyc_check: Option<KYCCheck>,
+    pub watchlist_screening_id: Option<String>,
+    /// Timestamp at which personally identifiable information was redacted,
+    /// in ISO 8601 format.
+    pub redacted_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IdentityVerificationTemplate {
+    pub id: String,
+    pub version: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IdentityVerificationUser {
+    pub phone_number: Option<String>,
+    pub date_of_birth: Option<String>,
+    pub ip_address: Option<String>,
+    pub email_address: Option<String>,
+    pub name: Option<IdentityVerificationName<String>>,
+    pub address: Option<IdentityVerificationAddress<String>>,
+    pub id_number: Option<IdentityVerificationIdNumber<String>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityVerificationStatus {
+    Active,
+    Success,
+    Failed,
+    Expired,
+    Canceled,
+    PendingReview,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IdentityVerificationSteps {
+    pub accept_tos: IdentityVerificationStepStatus,
+    pub verify_sms: IdentityVerificationStepStatus,
+    pub kyc_check: IdentityVerificationStepStatus,
+    pub documentary_verification: IdentityVerificationStepStatus,
+    pub selfie_check: IdentityVerificationStepStatus,
+    pub watchlist_screening: IdentityVerificationStepStatus,
+    pub risk_check: IdentityVerificationStepStatus,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityVerificationStepStatus {
+    Success,
+    Active,
+    Failed,
+    Waiting,
+    Skipped,
+    Expired,
+    Canceled,
+    PendingReview,
+    ManuallyApproved,
+    ManuallyRejected,
+    NotApplicable,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DocumentaryVerification {
+    /// The outcome of the documentary verification step, one of success,
+    /// failed or manually_approved.
+    pub status: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KYCCheck {
+    /// The outcome of the KYC check step, one of success, failed or
+    /// manually_approved.
+    pub status: String,
+    pub address: Option<KYCCheckMatch>,
+    pub name: Option<KYCCheckMatch>,
+    pub date_of_birth: Option<KYCCheckMatch>,
+    pub id_number: Option<KYCCheckMatch>,
+    pub phone_number: Option<KYCCheckMatch>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KYCCheckMatch {
+    /// One of match, partial_match, no_match, no_data or no_input.
+    pub summary: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetIdentityVerificationRequest<T: AsRef<str>> {
+    pub identity_verification_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetIdentityVerificationRequest<T> {
+    type Response = IdentityVerificationResponse;
+
+    fn path(&self) -> String {
+        "/identity_verification/get".into()
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ListIdentityVerificationsRequest<T: AsRef<str>> {
+    pub template_id: T,
+    pub client_user_id: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for ListIdentityVerificationsRequest<T> {
+    type Response = ListIdentityVerificationsResponse;
+
+    fn path(&self) -> String {
+        "/identity_verification/list".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListIdentityVerificationsResponse {
+    pub identity_verifications: Vec<IdentityVerification>,
+    /// Cursor for the next page of results, none when there are no more
+    /// results.
+    pub next_cursor: Option<String>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RetryIdentityVerificationRequest<T: AsRef<str>> {
+    pub client_user_id: T,
+    pub template_id: T,
+    pub strategy: IdentityVerificationRetryStrategy,
+    /// Steps to require when the strategy is `Custom`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps: Option<IdentityVerificationRetrySteps>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityVerificationRetryStrategy {
+    /// Restart the verification from the beginning.
+    Reset,
+    /// Start from the first incomplete step.
+    Incomplete,
+    /// Let Plaid decide which steps to retry.
+    Infer,
+    /// Retry the steps passed in `steps`.
+    Custom,
+}
+
+#[derive(Debug, Serialize, Clone, Copy)]
+pub struct IdentityVerificationRetrySteps {
+    pub verify_sms: bool,
+    pub kyc_check: bool,
+    pub documentary_verification: bool,
+    pub selfie_check: bool,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for RetryIdentityVerificationRequest<T> {
+    type Response = IdentityVerificationResponse;
+
+    fn path(&self) -> String {
+        "/identity_verification/retry".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_identity_verification() {
+        let res: IdentityVerificationResponse = serde_json::from_str(
+            r#"{
+                "id": "idv_52xR9LKo77r1Np",
+                "client_user_id": "your-db-id-3b24110",
+                "created_at": "2020-07-24T03:26:02Z",
+                "completed_at": null,
+                "previous_attempt_id": null,
+                "shareable_url": "https://flow.plaid.com/verify/idv_4FrXJvfQU3zGUR",
+                "template": {"id": "idvtmp_4FrXJvfQU3zGUR", "version": 2},
+                "user": {
+                    "phone_number": "+12345678909",
+                    "date_of_birth": "1990-05-29",
+                    "ip_address": "192.0.2.42",
+                    "email_address": "user@example.com",
+                    "name": {"given_name": "Leslie", "family_name": "Knope"},
+                    "address": {
+                        "street": "123 Main St.",
+                        "street2": null,
+                        "city": "Pawnee",
+                        "region": "IN",
+                        "postal_code": "46001",
+                        "country": "US"
+                    },
+                    "id_number": {"value": "123456789", "type": "us_ssn"}
+                },
+                "status": "success",
+                "steps": {
+                    "accept_tos": "success",
+                    "verify_sms": "success",
+                    "kyc_check": "success",
+                    "documentary_verification": "not_applicable",
+                    "selfie_check": "skipped",
+                    "watchlist_screening": "success",
+                    "risk_check": "success"
+                },
+                "documentary_verification": null,
+                "kyc_check": {
+                    "status": "success",
+                    "address": {"summary": "match"},
+                    "name": {"summary": "match"},
+                    "date_of_birth": {"summary": "match"},
+                    "id_number": {"summary": "match"},
+                    "phone_number": {"summary": "match"}
+                },
+                "watchlist_screening_id": "scr_52xR9LKo77r1Np",
+                "redacted_at": null,
+                "request_id": "saKrIBuEB9qJZng"
+            }"#,
+        )
+        .unwrap();
+
+        let verification = res.identity_verification;
+        assert_eq!(verification.status, IdentityVerificationStatus::Success);
+        assert_eq!(
+            verification.steps.documentary_verification,
+            IdentityVerificationStepStatus::NotApplicable
+        );
+        let address = verification.user.address.unwrap();
+        assert_eq!(address.city.as_deref(), Some("Pawnee"));
+        assert_eq!(address.street2, None);
+        assert_eq!(
+            verification.kyc_check.unwrap().name.unwrap().summary,
+            "match"
+        );
+    }
+
+    #[test]
+    fn omits_unset_address_fields() {
+        let address = IdentityVerificationAddress {
+            city: Some("Pawnee"),
+            country: "US",
+            ..IdentityVerificationAddress::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&address).unwrap(),
+            serde_json::json!({"city": "Pawnee", "country": "US"})
+        );
+    }
+}
@@ -0,0 +1,607 @@
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct GetBankIncomeRequest<T: AsRef<str>> {
+    pub user_token: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<GetBankIncomeOptions>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetBankIncomeOptions {
+    /// How many of the most recent bank income reports to return, defaults
+    /// to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetBankIncomeRequest<T> {
+    type Response = GetBankIncomeResponse;
+
+    fn path(&self) -> String {
+        "/credit/bank_income/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetBankIncomeResponse {
+    pub bank_income: Vec<BankIncome>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncome {
+    pub bank_income_id: String,
+    /// The time when the report was generated, in ISO 8601 format.
+    pub generated_time: Option<String>,
+    /// The number of days of transaction history requested.
+    pub days_requested: Option<usize>,
+    #[serde(default)]
+    pub items: Vec<BankIncomeItem>,
+    pub bank_income_summary: Option<BankIncomeSummary>,
+    #[serde(default)]
+    pub warnings: Vec<BankIncomeWarning>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncomeItem {
+    pub item_id: String,
+    pub institution_id: String,
+    pub institution_name: String,
+    /// The time when this Item's data was last retrieved from the financial
+    /// institution, in ISO 8601 format.
+    pub last_updated_time: String,
+    pub bank_income_accounts: Vec<BankIncomeAccount>,
+    pub bank_income_sources: Vec<BankIncomeSource>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncomeAccount {
+    pub account_id: String,
+    pub mask: Option<String>,
+    pub name: String,
+    pub official_name: Option<String>,
+    pub r#type: AccountType,
+    pub subtype: Option<String>,
+    #[serde(default)]
+    pub owners: Vec<Owner>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncomeSource {
+    pub income_source_id: String,
+    pub account_id: String,
+    /// The most common name or original description for the income
+    /// transactions.
+    pub income_description: String,
+    pub income_category: IncomeCategory,
+    /// Date of the earliest income transaction for this source (YYYY-MM-DD).
+    pub start_date: String,
+    /// Date of the latest income transaction for this source (YYYY-MM-DD).
+    pub end_date: String,
+    pub pay_frequency: IncomePayFrequency,
+    #[cfg(not(feature = "decimal"))]
+    pub total_amount: f64,
+    #[cfg(feature = "decimal")]
+    pub total_amount: Decimal,
+    pub transaction_count: usize,
+    #[serde(default)]
+    pub historical_summary: Vec<BankIncomeHistoricalSummary>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeCategory {
+    Salary,
+    Unemployment,
+    Cash,
+    GigEconomy,
+    Rental,
+    ChildSupport,
+    Military,
+    Retirement,
+    LongTermDisability,
+    BankInterest,
+    CashDeposit,
+    TransferFromApplication,
+    TaxRefund,
+    BenefitOther,
+    Other,
+    /// A category introduced by Plaid after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomePayFrequency {
+    Weekly,
+    Biweekly,
+    SemiMonthly,
+    Monthly,
+    Daily,
+    /// Plaid couldn't determine the frequency, also used for frequencies
+    /// introduced after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncomeHistoricalSummary {
+    #[cfg(not(feature = "decimal"))]
+    pub total_amount: f64,
+    #[cfg(feature = "decimal")]
+    pub total_amount: Decimal,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+    pub start_date: String,
+    pub end_date: String,
+    #[serde(default)]
+    pub transactions: Vec<BankIncomeTransaction>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncomeTransaction {
+    pub transaction_id: String,
+    #[cfg(not(feature = "decimal"))]
+    pub amount: f64,
+    #[cfg(feature = "decimal")]
+    pub amount: Decimal,
+    pub date: String,
+    pub name: String,
+    pub original_description: Option<String>,
+    pub pending: bool,
+    pub check_number: Option<String>,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncomeSummary {
+    #[cfg(not(feature = "decimal"))]
+    pub total_amount: f64,
+    #[cfg(feature = "decimal")]
+    pub total_amount: Decimal,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+    pub start_date: String,
+    pub end_date: String,
+    pub income_sources_count: usize,
+    pub income_categories_count: usize,
+    pub income_transactions_count: usize,
+    #[serde(default)]
+    pub historical_summary: Vec<BankIncomeHistoricalSummary>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncomeWarning {
+    pub warning_type: String,
+    pub warning_code: String,
+    pub cause: Option<BankIncomeWarningCause>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BankIncomeWarningCause {
+    pub error_type: Option<ErrorType>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub display_message: Option<String>,
+    pub item_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefreshBankIncomeRequest<T: AsRef<str>> {
+    pub user_token: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<RefreshBankIncomeOptions<T>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefreshBankIncomeOptions<T: AsRef<str>> {
+    /// Number of days of transaction history to retrieve, defaults to the
+    /// number of days requested when the report was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_requested: Option<usize>,
+    /// URL to receive the `BANK_INCOME_REFRESH_COMPLETE` webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for RefreshBankIncomeRequest<T> {
+    type Response = RefreshBankIncomeResponse;
+
+    fn path(&self) -> String {
+        "/credit/bank_income/refresh".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RefreshBankIncomeResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetPayrollIncomeRequest<T: AsRef<str>> {
+    pub user_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetPayrollIncomeRequest<T> {
+    type Response = GetPayrollIncomeResponse;
+
+    fn path(&self) -> String {
+        "/credit/payroll_income/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetPayrollIncomeResponse {
+    pub items: Vec<PayrollItem>,
+    pub error: Option<ErrorResponse>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollItem {
+    pub item_id: String,
+    pub institution_id: Option<String>,
+    pub institution_name: Option<String>,
+    #[serde(default)]
+    pub accounts: Vec<PayrollIncomeAccount>,
+    pub payroll_income: Vec<PayrollIncome>,
+    pub status: Option<PayrollItemStatus>,
+    /// Timestamp of the last time the Item's data was updated, in ISO 8601
+    /// format.
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollIncomeAccount {
+    pub account_id: Option<String>,
+    pub rate_of_pay: Option<PayrollRateOfPay>,
+    pub pay_frequency: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollRateOfPay {
+    /// One of ANNUAL, HOURLY, CONTRACT or OTHER.
+    pub pay_rate: Option<String>,
+    #[cfg(not(feature = "decimal"))]
+    pub pay_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub pay_amount: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollItemStatus {
+    /// One of PROCESSING_COMPLETE, PROCESSING or FAILED.
+    pub processing_status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollIncome {
+    pub account_id: Option<String>,
+    pub pay_stubs: Vec<PayStub>,
+    pub w2s: Vec<W2>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollDocumentMetadata {
+    pub name: String,
+    /// The type of document, e.g. PAYSTUB or US_TAX_W2.
+    pub document_type: Option<String>,
+    /// Signed URL to retrieve the underlying file, only returned for
+    /// documents uploaded by the user.
+    pub download_url: Option<String>,
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStub {
+    pub document_id: Option<String>,
+    pub document_metadata: PayrollDocumentMetadata,
+    pub deductions: PayStubDeductions,
+    pub earnings: PayStubEarnings,
+    pub employee: PayrollEmployee,
+    pub employer: PayrollEmployer,
+    pub net_pay: PayStubNetPay,
+    pub pay_period_details: PayStubPayPeriodDetails,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubDeductions {
+    #[serde(default)]
+    pub breakdown: Vec<PayStubDeductionsBreakdown>,
+    pub total: PayStubDeductionsTotal,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubDeductionsBreakdown {
+    pub description: Option<String>,
+    #[cfg(not(feature = "decimal"))]
+    pub current_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub current_amount: Option<Decimal>,
+    #[cfg(not(feature = "decimal"))]
+    pub ytd_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub ytd_amount: Option<Decimal>,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubDeductionsTotal {
+    #[cfg(not(feature = "decimal"))]
+    pub current_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub current_amount: Option<Decimal>,
+    #[cfg(not(feature = "decimal"))]
+    pub ytd_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub ytd_amount: Option<Decimal>,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubEarnings {
+    #[serde(default)]
+    pub breakdown: Vec<PayStubEarningsBreakdown>,
+    pub total: PayStubEarningsTotal,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubEarningsBreakdown {
+    /// Commonly used term to describe the earning line item, e.g. BONUS,
+    /// COMMISSION or REGULAR_PAY.
+    pub canonical_description: Option<String>,
+    pub description: Option<String>,
+    #[cfg(not(feature = "decimal"))]
+    pub current_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub current_amount: Option<Decimal>,
+    #[cfg(not(feature = "decimal"))]
+    pub ytd_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub ytd_amount: Option<Decimal>,
+    pub hours: Option<f64>,
+    #[cfg(not(feature = "decimal"))]
+    pub rate: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub rate: Option<Decimal>,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubEarningsTotal {
+    #[cfg(not(feature = "decimal"))]
+    pub current_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub current_amount: Option<Decimal>,
+    #[cfg(not(feature = "decimal"))]
+    pub ytd_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub ytd_amount: Option<Decimal>,
+    pub hours: Option<f64>,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubNetPay {
+    pub description: Option<String>,
+    #[cfg(not(feature = "decimal"))]
+    pub current_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub current_amount: Option<Decimal>,
+    #[cfg(not(feature = "decimal"))]
+    pub ytd_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub ytd_amount: Option<Decimal>,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubPayPeriodDetails {
+    /// Date on which the pay stub was issued (YYYY-MM-DD).
+    pub pay_date: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub pay_frequency: Option<String>,
+    #[cfg(not(feature = "decimal"))]
+    pub gross_earnings: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub gross_earnings: Option<Decimal>,
+    #[cfg(not(feature = "decimal"))]
+    pub check_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub check_amount: Option<Decimal>,
+    #[serde(default)]
+    pub distribution_breakdown: Vec<PayStubDistribution>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayStubDistribution {
+    pub account_name: Option<String>,
+    pub bank_name: Option<String>,
+    #[cfg(not(feature = "decimal"))]
+    pub current_amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub current_amount: Option<Decimal>,
+    pub mask: Option<String>,
+    pub r#type: Option<String>,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollEmployee {
+    pub name: Option<String>,
+    pub address: PayrollAddress,
+    pub marital_status: Option<String>,
+    pub taxpayer_id: Option<PayrollTaxpayerId>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollTaxpayerId {
+    /// Type of the ID, e.g. SSN.
+    pub id_type: Option<String>,
+    /// The last 4 digits of the ID.
+    pub id_mask: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollEmployer {
+    pub name: Option<String>,
+    pub address: Option<PayrollAddress>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayrollAddress {
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub postal_code: Option<String>,
+    pub region: Option<String>,
+    pub street: Option<String>,
+}
+
+/// A W-2 wage and tax statement. Amounts are returned as strings as they
+/// appear on the form.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct W2 {
+    pub document_id: String,
+    pub document_metadata: PayrollDocumentMetadata,
+    pub employer: PayrollEmployer,
+    pub employee: PayrollEmployee,
+    pub tax_year: Option<String>,
+    pub employer_id_number: Option<String>,
+    pub wages_tips_other_comp: Option<String>,
+    pub federal_income_tax_withheld: Option<String>,
+    pub social_security_wages: Option<String>,
+    pub social_security_tax_withheld: Option<String>,
+    pub medicare_wages_and_tips: Option<String>,
+    pub medicare_tax_withheld: Option<String>,
+    pub social_security_tips: Option<String>,
+    pub allocated_tips: Option<String>,
+    pub box_9: Option<String>,
+    pub dependent_care_benefits: Option<String>,
+    pub nonqualified_plans: Option<String>,
+    #[serde(default)]
+    pub box_12: Vec<W2Box12>,
+    pub statutory_employee: Option<String>,
+    pub retirement_plan: Option<String>,
+    pub third_party_sick_pay: Option<String>,
+    pub other: Option<String>,
+    #[serde(default)]
+    pub state_and_local_wages: Vec<W2StateAndLocalWages>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct W2Box12 {
+    pub code: Option<String>,
+    pub amount: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct W2StateAndLocalWages {
+    pub state: Option<String>,
+    pub employer_state_id_number: Option<String>,
+    pub state_wages_tips: Option<String>,
+    pub state_income_tax: Option<String>,
+    pub local_wages_tips: Option<String>,
+    pub local_income_tax: Option<String>,
+    pub locality_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetCreditSessionsRequest<T: AsRef<str>> {
+    pub user_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetCreditSessionsRequest<T> {
+    type Response = GetCreditSessionsResponse;
+
+    fn path(&self) -> String {
+        "/credit/sessions/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetCreditSessionsResponse {
+    pub sessions: Vec<CreditSession>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreditSession {
+    pub link_session_id: String,
+    /// The time when the Link session started, in ISO 8601 format.
+    pub session_start_time: String,
+    pub results: Option<CreditSessionResults>,
+    #[serde(default)]
+    pub errors: Vec<ErrorResponse>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreditSessionResults {
+    #[serde(default)]
+    pub item_add_results: Vec<CreditSessionItemAddResult>,
+    #[serde(default)]
+    pub bank_income_results: Vec<CreditSessionBankIncomeResult>,
+    #[serde(default)]
+    pub payroll_income_results: Vec<CreditSessionPayrollIncomeResult>,
+    pub document_income_results: Option<CreditSessionDocumentIncomeResult>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreditSessionItemAddResult {
+    pub public_token: Option<String>,
+    pub item_id: Option<String>,
+    pub institution_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreditSessionBankIncomeResult {
+    /// One of APPROVED, NO_DEPOSITS_FOUND or USER_REPORTED_NO_INCOME.
+    pub status: Option<String>,
+    pub item_id: Option<String>,
+    pub institution_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreditSessionPayrollIncomeResult {
+    pub num_paystubs_retrieved: Option<usize>,
+    pub num_w2s_retrieved: Option<usize>,
+    pub institution_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreditSessionDocumentIncomeResult {
+    pub num_paystubs_uploaded: Option<usize>,
+    pub num_w2s_uploaded: Option<usize>,
+    pub num_bank_statements_uploaded: Option<usize>,
+    pub num_1099s_uploaded: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unknown_categories_and_frequencies() {
+        let category: IncomeCategory = serde_json::from_str(r#""CRYPTO_STAKING""#).unwrap();
+        assert_eq!(category, IncomeCategory::Unknown);
+        let category: IncomeCategory = serde_json::from_str(r#""GIG_ECONOMY""#).unwrap();
+        assert_eq!(category, IncomeCategory::GigEconomy);
+
+        let frequency: IncomePayFrequency = serde_json::from_str(r#""QUARTERLY""#).unwrap();
+        assert_eq!(frequency, IncomePayFrequency::Unknown);
+        let frequency: IncomePayFrequency = serde_json::from_str(r#""UNKNOWN""#).unwrap();
+        assert_eq!(frequency, IncomePayFrequency::Unknown);
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use super::*;
 
 #[derive(Debug, Serialize)]
@@ -63,7 +65,7 @@ pub struct InstitutionGetResponse {
     pub institution: Institution,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 pub struct InstitutionsGetRequest<'a, T: AsRef<str>> {
     pub count: usize,
     pub offset: usize,
@@ -72,7 +74,7 @@ pub struct InstitutionsGetRequest<'a, T: AsRef<str>> {
     pub options: Option<GetInstitutionsFilter<'a, T>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 pub struct GetInstitutionsFilter<'a, T: AsRef<str>> {
     /// Filter the Institutions based on which products they support.
     pub products: &'a [T],
@@ -101,9 +103,11 @@ impl<T: AsRef<str> + serde::Serialize> Endpoint for InstitutionsGetRequest<'_, T
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InstitutionsGetResponse {
     pub institutions: Vec<Institution>,
+    /// The total number of institutions available through the endpoint.
+    pub total: usize,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Institution {
     pub institution_id: String,
     pub name: String,
@@ -114,4 +118,243 @@ pub struct Institution {
     pub logo: Option<String>,
     pub routing_numbers: Option<Vec<String>>,
     pub oauth: bool,
+    /// Only returned when `include_status` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InstitutionStatus>,
+    /// Only returned when `include_auth_metadata` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_metadata: Option<AuthMetadata>,
+    /// Only returned when `include_payment_initiation_metadata` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_initiation_metadata: Option<PaymentInitiationMetadata>,
+}
+
+/// The health of an institution's integration, broken down by product.
+/// Products the institution does not support are `None`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InstitutionStatus {
+    /// Health of new Item creation and Link logins.
+    pub item_logins: Option<ProductStatus>,
+    /// Health of transactions updates for existing Items.
+    pub transactions_updates: Option<ProductStatus>,
+    pub auth: Option<ProductStatus>,
+    pub identity: Option<ProductStatus>,
+    /// Health of investments data for newly created Items.
+    pub investments: Option<ProductStatus>,
+    /// Health of investments updates for existing Items.
+    pub investments_updates: Option<ProductStatus>,
+    pub liabilities: Option<ProductStatus>,
+    pub liabilities_updates: Option<ProductStatus>,
+    pub health_incidents: Option<Vec<HealthIncident>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProductStatus {
+    pub status: ProductHealth,
+    /// Timestamp of the last status change, in ISO 8601 format.
+    pub last_status_change: String,
+    pub breakdown: ProductStatusBreakdown,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProductHealth {
+    Healthy,
+    Degraded,
+    Down,
+}
+
+/// Share of requests by outcome over the last few minutes, each between 0
+/// and 1.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct ProductStatusBreakdown {
+    pub success: f64,
+    /// Requests that failed due to an error on Plaid's side.
+    pub error_plaid: f64,
+    /// Requests that failed due to an error on the institution's side.
+    pub error_institution: f64,
+    /// Only returned for transactions updates.
+    pub refresh_interval: Option<RefreshInterval>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RefreshInterval {
+    Normal,
+    Delayed,
+    Stopped,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthIncident {
+    /// Start of the incident, in ISO 8601 format.
+    pub start_date: String,
+    /// End of the incident, none while the incident is ongoing.
+    pub end_date: Option<String>,
+    pub title: String,
+    pub incident_updates: Vec<IncidentUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IncidentUpdate {
+    pub description: String,
+    /// One of INVESTIGATING, IDENTIFIED, SCHEDULED, RESOLVED or UNKNOWN.
+    pub status: String,
+    pub updated_date: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuthMetadata {
+    pub supported_methods: Option<AuthSupportedMethods>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct AuthSupportedMethods {
+    pub instant_auth: bool,
+    pub instant_match: bool,
+    pub automated_micro_deposits: bool,
+    pub instant_micro_deposits: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PaymentInitiationMetadata {
+    pub supports_international_payments: bool,
+    pub supports_sepa_instant: bool,
+    /// Maximum payment amount per currency, keyed by ISO 4217 currency code.
+    pub maximum_payment_amount: HashMap<String, String>,
+    pub supports_refund_details: bool,
+    pub standing_order_metadata: Option<StandingOrderMetadata>,
+    #[serde(default)]
+    pub supports_payment_consents: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StandingOrderMetadata {
+    pub supports_standing_order_end_date: bool,
+    pub supports_standing_order_negative_execution_days: bool,
+    pub valid_standing_order_intervals: Vec<PaymentScheduleInterval>,
+}
+
+/// An in-memory index of institutions supporting lookups by ID and routing
+/// number and case-insensitive name search. Typically built from the pages of
+/// `Plaid::institutions_iter`.
+#[derive(Debug, Default, Clone)]
+pub struct InstitutionIndex {
+    institutions: Vec<Institution>,
+    names: Vec<String>,
+    by_id: HashMap<String, usize>,
+    by_routing_number: HashMap<String, Vec<usize>>,
+}
+
+impl InstitutionIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an institution to the index, replacing any institution with the
+    /// same ID.
+    pub fn insert(&mut self, institution: Institution) {
+        if let Some(&i) = self.by_id.get(&institution.institution_id) {
+            for routing_number in self.institutions[i].routing_numbers.iter().flatten() {
+                if let Some(indices) = self.by_routing_number.get_mut(routing_number) {
+                    indices.retain(|&j| j != i);
+                }
+            }
+            self.index_routing_numbers(i, &institution);
+            self.names[i] = institution.name.to_lowercase();
+            self.institutions[i] = institution;
+            return;
+        }
+
+        let i = self.institutions.len();
+        self.by_id.insert(institution.institution_id.clone(), i);
+        self.index_routing_numbers(i, &institution);
+        self.names.push(institution.name.to_lowercase());
+        self.institutions.push(institution);
+    }
+
+    fn index_routing_numbers(&mut self, i: usize, institution: &Institution) {
+        for routing_number in institution.routing_numbers.iter().flatten() {
+            self.by_routing_number
+                .entry(routing_number.clone())
+                .or_default()
+                .push(i);
+        }
+    }
+
+    pub fn get(&self, institution_id: &str) -> Option<&Institution> {
+        self.by_id
+            .get(institution_id)
+            .map(|&i| &self.institutions[i])
+    }
+
+    /// Returns institutions that list the given routing number.
+    pub fn by_routing_number(&self, routing_number: &str) -> Vec<&Institution> {
+        self.by_routing_number
+            .get(routing_number)
+            .map(|indices| indices.iter().map(|&i| &self.institutions[i]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns institutions whose name contains every whitespace separated
+    /// term in `query`, ignoring case. Exact matches are returned first,
+    /// followed by names starting with the query, then all other matches.
+    pub fn search(&self, query: &str) -> Vec<&Institution> {
+        let query = query.trim().to_lowercase();
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        if terms.is_empty() {
+            return vec![];
+        }
+
+        let mut matches: Vec<(u8, usize)> = self
+            .names
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| terms.iter().all(|term| name.contains(term)))
+            .map(|(i, name)| {
+                let rank = if *name == query {
+                    0
+                } else if name.starts_with(&query) {
+                    1
+                } else {
+                    2
+                };
+                (rank, i)
+            })
+            .collect();
+        matches.sort();
+
+        matches
+            .into_iter()
+            .map(|(_, i)| &self.institutions[i])
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.institutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.institutions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Institution> {
+        self.institutions.iter()
+    }
+}
+
+impl FromIterator<Institution> for InstitutionIndex {
+    fn from_iter<I: IntoIterator<Item = Institution>>(iter: I) -> Self {
+        let mut index = Self::new();
+        index.extend(iter);
+        index
+    }
+}
+
+impl Extend<Institution> for InstitutionIndex {
+    fn extend<I: IntoIterator<Item = Institution>>(&mut self, iter: I) {
+        for institution in iter {
+            self.insert(institution);
+        }
+    }
 }
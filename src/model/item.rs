@@ -20,6 +20,14 @@ pub struct GetItemResponse {
     pub request_id: String,
 }
 
+impl GetItemResponse {
+    /// Summarizes the health of the Item, `now` is the current unix time in
+    /// seconds.
+    pub fn health(&self, now: u64) -> ItemHealth {
+        ItemHealth::new(&self.item, self.status.as_ref(), now)
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct RemoveItemRequest<T: AsRef<str>> {
     pub access_token: T,
@@ -67,12 +75,49 @@ pub struct Item {
     pub error: Option<ErrorResponse>,
     pub available_products: Vec<String>,
     pub billed_products: Vec<String>,
-    // An RFC 3339 timestamp after which the consent provided by the end user will expire.
+    /// Products that have been added to the Item, billed or not.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub products: Vec<String>,
+    /// Products the end user has consented to share data for, which may
+    /// include products not yet added to the Item.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consented_products: Vec<String>,
+    /// An RFC 3339 timestamp after which the consent provided by the end user
+    /// will expire. Only set for institutions with time limited consent, e.g.
+    /// European institutions under PSD2.
     pub consent_expiration_time: Option<String>,
-    pub update_type: String,
+    pub update_type: ItemUpdateType,
     pub status: Option<Status>,
 }
 
+impl Item {
+    /// Returns the unix time, in seconds, the end user's consent expires at.
+    pub fn consent_expires_at(&self) -> Option<u64> {
+        self.consent_expiration_time
+            .as_deref()
+            .and_then(parse_rfc3339)
+    }
+
+    /// Summarizes the health of the Item, `now` is the current unix time in
+    /// seconds.
+    pub fn health(&self, now: u64) -> ItemHealth {
+        ItemHealth::new(self, self.status.as_ref(), now)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemUpdateType {
+    /// The Item can be updated in the background.
+    Background,
+    /// The Item requires the end user to be present to update, e.g. because
+    /// of MFA on every login.
+    UserPresentRequired,
+    /// An update type introduced by Plaid after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Status {
     pub investments: Option<StatusMessage>,
@@ -80,14 +125,385 @@ pub struct Status {
     pub last_webhook: Option<WebhookStatus>,
 }
 
+impl Status {
+    /// Returns true when the most recent transactions or investments update
+    /// failed.
+    pub fn is_failing(&self) -> bool {
+        [&self.transactions, &self.investments]
+            .into_iter()
+            .flatten()
+            .any(StatusMessage::is_failing)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct StatusMessage {
     pub last_successful_update: Option<String>,
     pub last_failed_update: Option<String>,
 }
 
+impl StatusMessage {
+    /// Returns true when the last failed update is more recent than the last
+    /// successful one.
+    pub fn is_failing(&self) -> bool {
+        let failed = self.last_failed_update.as_deref().and_then(parse_rfc3339);
+        let succeeded = self
+            .last_successful_update
+            .as_deref()
+            .and_then(parse_rfc3339);
+        match (failed, succeeded) {
+            (Some(failed), Some(succeeded)) => failed > succeeded,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct WebhookStatus {
     pub sent_at: Option<String>,
     pub code_sent: Option<String>,
 }
+
+/// Time before consent expires at which Plaid sends a `PENDING_EXPIRATION`
+/// webhook and `ItemHealth` reports the Item as expiring.
+pub const CONSENT_EXPIRATION_WARNING_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// A summary of whether an Item needs attention, from most to least severe.
+/// Built from `Item::error`, the consent expiration time and the update
+/// timestamps in `Status`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ItemHealth {
+    /// The end user's consent has expired, the Item must go through Link's
+    /// update mode to be used again.
+    ConsentExpired,
+    /// The Item's credentials are no longer valid, the end user must go
+    /// through Link's update mode.
+    LoginRequired(ErrorResponse),
+    /// The Item is in an error state that doesn't require the end user.
+    Error(ErrorResponse),
+    /// Consent expires within `CONSENT_EXPIRATION_WARNING_SECS`, at the given
+    /// unix time.
+    ConsentExpiring(u64),
+    /// The most recent transactions or investments update failed.
+    UpdatesFailing,
+    Healthy,
+}
+
+impl ItemHealth {
+    /// Error codes that can only be resolved by the end user in update mode.
+    const USER_ACTION_ERRORS: &'static [&'static str] = &[
+        "ITEM_LOGIN_REQUIRED",
+        "INVALID_CREDENTIALS",
+        "INVALID_MFA",
+        "INVALID_UPDATED_USERNAME",
+        "ITEM_LOCKED",
+        "USER_SETUP_REQUIRED",
+        "ACCESS_NOT_GRANTED",
+        "INSUFFICIENT_CREDENTIALS",
+        "NO_ACCOUNTS",
+    ];
+
+    /// Summarizes an Item's health given its status, `now` is the current
+    /// unix time in seconds.
+    pub fn new(item: &Item, status: Option<&Status>, now: u64) -> Self {
+        let expires_at = item.consent_expires_at();
+        if matches!(expires_at, Some(expires_at) if expires_at <= now) {
+            return Self::ConsentExpired;
+        }
+
+        if let Some(error) = &item.error {
+            let code = error.error_code.as_deref().unwrap_or_default();
+            if Self::USER_ACTION_ERRORS.contains(&code) {
+                return Self::LoginRequired(error.clone());
+            }
+            return Self::Error(error.clone());
+        }
+
+        if let Some(expires_at) = expires_at {
+            if expires_at - now <= CONSENT_EXPIRATION_WARNING_SECS {
+                return Self::ConsentExpiring(expires_at);
+            }
+        }
+
+        if status
+            .or(item.status.as_ref())
+            .is_some_and(Status::is_failing)
+        {
+            return Self::UpdatesFailing;
+        }
+
+        Self::Healthy
+    }
+
+    /// Returns true when the end user has to go through Link's update mode to
+    /// fix or keep the Item working.
+    pub fn needs_update_mode(&self) -> bool {
+        matches!(
+            self,
+            Self::ConsentExpired | Self::LoginRequired(_) | Self::ConsentExpiring(_)
+        )
+    }
+}
+
+/// Parses an RFC 3339 timestamp, e.g. "2022-06-01T12:00:00Z", into unix
+/// seconds. Fractional seconds are ignored.
+fn parse_rfc3339(timestamp: &str) -> Option<u64> {
+    let (date, time) = timestamp.split_once(['T', 't', ' '])?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        let i = time.rfind(['+', '-'])?;
+        let (hours, minutes) = time[i + 1..].split_once(':')?;
+        let offset = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+        let sign = if &time[i..=i] == "-" { -1 } else { 1 };
+        (&time[..i], sign * offset)
+    };
+    let time = time.split('.').next()?;
+    let mut time = time.splitn(3, ':').map(str::parse::<i64>);
+    let (hour, minute, second) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Days since the unix epoch for a proleptic Gregorian date.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second - offset;
+    u64::try_from(secs).ok()
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListItemApplicationsRequest<T: AsRef<str>> {
+    /// Lists applications connected to this Item, or to every Item of the
+    /// end user when none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for ListItemApplicationsRequest<T> {
+    type Response = ListItemApplicationsResponse;
+
+    fn path(&self) -> String {
+        "/item/application/list".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListItemApplicationsResponse {
+    pub applications: Vec<ConnectedApplication>,
+    pub request_id: String,
+}
+
+/// A third party application the end user has shared data from the Item with.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ConnectedApplication {
+    pub application_id: String,
+    pub name: String,
+    pub display_name: Option<String>,
+    pub logo_url: Option<String>,
+    pub application_url: Option<String>,
+    pub reason_for_access: Option<String>,
+    /// When the connection was created, in ISO 8601 format.
+    pub created_at: String,
+    pub scopes: Option<ApplicationScopes>,
+}
+
+/// The data an application can access on an Item.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ApplicationScopes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_access: Option<ProductAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts: Option<Vec<AccountAccess>>,
+    /// Whether accounts added to the Item later are shared with the
+    /// application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_accounts: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy)]
+pub struct ProductAccess {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statements: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts_details_transactions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts_routing_number: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts_statements: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts_tax_statements: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customers_profiles: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AccountAccess {
+    /// The account's persistent identifier, not its `account_id`.
+    pub unique_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_product_access: Option<AccountProductAccess>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy)]
+pub struct AccountProductAccess {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_data: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statements: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_documents: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateItemApplicationScopesRequest<T: AsRef<str>> {
+    pub access_token: T,
+    pub application_id: T,
+    pub scopes: ApplicationScopes,
+    /// The `state` from the OAuth redirect, required when `context` is
+    /// `Enrollment`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<T>,
+    pub context: ScopesContext,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScopesContext {
+    /// Scopes granted while the end user links the application.
+    Enrollment,
+    /// Scopes changed later from a permissions portal.
+    Portal,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for UpdateItemApplicationScopesRequest<T> {
+    type Response = UpdateItemApplicationScopesResponse;
+
+    fn path(&self) -> String {
+        "/item/application/scopes/update".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateItemApplicationScopesResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnlinkItemApplicationRequest<T: AsRef<str>> {
+    pub access_token: T,
+    pub application_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for UnlinkItemApplicationRequest<T> {
+    type Response = UnlinkItemApplicationResponse;
+
+    fn path(&self) -> String {
+        "/item/application/unlink".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UnlinkItemApplicationResponse {
+    pub request_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_654_084_800; // 2022-06-01T12:00:00Z
+
+    fn item(error_code: Option<&str>, consent_expiration_time: Option<&str>) -> Item {
+        Item {
+            item_id: "item".into(),
+            institution_id: None,
+            webhook: None,
+            error: error_code.map(|code| ErrorResponse {
+                error_code: Some(code.into()),
+                ..ErrorResponse::default()
+            }),
+            available_products: vec![],
+            billed_products: vec![],
+            products: vec![],
+            consented_products: vec![],
+            consent_expiration_time: consent_expiration_time.map(Into::into),
+            update_type: ItemUpdateType::Background,
+            status: None,
+        }
+    }
+
+    #[test]
+    fn parses_rfc3339_timestamps() {
+        assert_eq!(parse_rfc3339("2022-06-01T12:00:00Z"), Some(NOW));
+        assert_eq!(parse_rfc3339("2022-06-01T12:00:00.123Z"), Some(NOW));
+        assert_eq!(parse_rfc3339("2022-06-01T14:00:00+02:00"), Some(NOW));
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_rfc3339("2022-06-01"), None);
+    }
+
+    #[test]
+    fn summarizes_item_health() {
+        assert_eq!(item(None, None).health(NOW), ItemHealth::Healthy);
+        assert_eq!(
+            item(None, Some("2022-06-01T11:00:00Z")).health(NOW),
+            ItemHealth::ConsentExpired
+        );
+        assert_eq!(
+            item(None, Some("2022-06-03T12:00:00Z")).health(NOW),
+            ItemHealth::ConsentExpiring(NOW + 2 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            item(None, Some("2022-07-01T12:00:00Z")).health(NOW),
+            ItemHealth::Healthy
+        );
+
+        let login_required = item(Some("ITEM_LOGIN_REQUIRED"), None).health(NOW);
+        assert!(matches!(login_required, ItemHealth::LoginRequired(_)));
+        assert!(login_required.needs_update_mode());
+        let error = item(Some("INSTITUTION_DOWN"), None).health(NOW);
+        assert!(matches!(error, ItemHealth::Error(_)));
+        assert!(!error.needs_update_mode());
+    }
+
+    #[test]
+    fn parses_unknown_update_types() {
+        let update_type: ItemUpdateType =
+            serde_json::from_str(r#""user_present_optional""#).unwrap();
+        assert_eq!(update_type, ItemUpdateType::Unknown);
+    }
+
+    #[test]
+    fn reports_failing_updates() {
+        let status = Status {
+            investments: None,
+            transactions: Some(StatusMessage {
+                last_successful_update: Some("2022-05-31T12:00:00Z".into()),
+                last_failed_update: Some("2022-06-01T10:00:00Z".into()),
+            }),
+            last_webhook: None,
+        };
+
+        assert_eq!(
+            ItemHealth::new(&item(None, None), Some(&status), NOW),
+            ItemHealth::UpdatesFailing
+        );
+    }
+}
@@ -2,13 +2,24 @@ mod account;
 mod auth;
 mod balance;
 mod common;
+mod deposit_switch;
 mod employers;
+mod enrich;
 mod identity;
+mod identity_verification;
+mod income;
 mod institutions;
 mod item;
+mod payment_initiation;
+mod personal_finance_category;
+mod processor;
 mod sandbox;
+mod signal;
+mod statements;
 mod token;
 mod transactions;
+mod user;
+mod watchlist_screening;
 mod webhooks;
 
 use serde::{Deserialize, Serialize};
@@ -17,11 +28,22 @@ pub use account::*;
 pub use auth::*;
 pub use balance::*;
 pub use common::*;
+pub use deposit_switch::*;
 pub use employers::*;
+pub use enrich::*;
 pub use identity::*;
+pub use identity_verification::*;
+pub use income::*;
 pub use institutions::*;
 pub use item::*;
+pub use payment_initiation::*;
+pub use personal_finance_category::*;
+pub use processor::*;
 pub use sandbox::*;
+pub use signal::*;
+pub use statements::*;
 pub use token::*;
 pub use transactions::*;
+pub use user::*;
+pub use watchlist_screening::*;
 pub use webhooks::*;
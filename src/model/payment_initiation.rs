@@ -0,0 +1,559 @@
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct CreatePaymentRecipientRequest<'a, T: AsRef<str>> {
+    /// The name of the recipient.
+    pub name: T,
+    /// The International Bank Account Number (IBAN) for the recipient, one of
+    /// `iban` or `bacs` must be provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iban: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bacs: Option<RecipientBACS<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<PaymentInitiationAddress<'a, T>>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreatePaymentRecipientRequest<'_, T> {
+    type Response = CreatePaymentRecipientResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/recipient/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreatePaymentRecipientResponse {
+    pub recipient_id: String,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetPaymentRecipientRequest<T: AsRef<str>> {
+    pub recipient_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetPaymentRecipientRequest<T> {
+    type Response = GetPaymentRecipientResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/recipient/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetPaymentRecipientResponse {
+    #[serde(flatten)]
+    pub recipient: PaymentRecipient,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize, Copy, Clone)]
+pub struct ListPaymentRecipientsRequest {}
+
+impl Endpoint for ListPaymentRecipientsRequest {
+    type Response = ListPaymentRecipientsResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/recipient/list".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListPaymentRecipientsResponse {
+    pub recipients: Vec<PaymentRecipient>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaymentRecipient {
+    pub recipient_id: String,
+    pub name: String,
+    pub address: Option<RecipientAddress>,
+    pub iban: Option<String>,
+    pub bacs: Option<RecipientBACS<String>>,
+}
+
+/// Account number and sort code used to identify a UK recipient over the
+/// Bacs payment scheme.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RecipientBACS<T: AsRef<str>> {
+    pub account: T,
+    pub sort_code: T,
+}
+
+impl<'a> From<&'a BACSAccountNumber> for RecipientBACS<&'a str> {
+    fn from(numbers: &'a BACSAccountNumber) -> Self {
+        Self {
+            account: &numbers.account,
+            sort_code: &numbers.sort_code,
+        }
+    }
+}
+
+impl<'a> CreatePaymentRecipientRequest<'a, &'a str> {
+    /// Creates a recipient identified by the IBAN of an account returned by
+    /// `/auth/get`.
+    pub fn with_international(name: &'a str, numbers: &'a InternationalAccountNumber) -> Self {
+        Self {
+            name,
+            iban: Some(&numbers.iban),
+            bacs: None,
+            address: None,
+        }
+    }
+
+    /// Creates a recipient identified by the BACS numbers of an account
+    /// returned by `/auth/get`.
+    pub fn with_bacs(name: &'a str, numbers: &'a BACSAccountNumber) -> Self {
+        Self {
+            name,
+            iban: None,
+            bacs: Some(numbers.into()),
+            address: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaymentInitiationAddress<'a, T: AsRef<str>> {
+    /// An array of length 1-2 representing the street address where the
+    /// recipient is located.
+    pub street: &'a [T],
+    pub city: T,
+    pub postal_code: T,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: T,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RecipientAddress {
+    pub street: Vec<String>,
+    pub city: String,
+    pub postal_code: String,
+    pub country: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+pub enum PaymentCurrency {
+    #[serde(rename = "GBP")]
+    Gbp,
+    #[serde(rename = "EUR")]
+    Eur,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct PaymentAmount {
+    pub currency: PaymentCurrency,
+    #[cfg(not(feature = "decimal"))]
+    pub value: f64,
+    #[cfg(feature = "decimal")]
+    #[serde(with = "rust_decimal::serde::float")]
+    pub value: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatePaymentRequest<T: AsRef<str>> {
+    pub recipient_id: T,
+    /// A reference for the payment. Must be between 1 and 18 characters and
+    /// only contain alphanumeric characters, spaces, and hyphens.
+    pub reference: T,
+    pub amount: PaymentAmount,
+    /// Makes the payment recurring when provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<PaymentSchedule<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<CreatePaymentOptions<T>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatePaymentOptions<T: AsRef<str>> {
+    /// When true, Plaid will attempt to request refund details from the
+    /// payee's financial institution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_refund_details: Option<bool>,
+    /// Restricts the payment to be made from the account with this IBAN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iban: Option<T>,
+    /// Restricts the payment to be made from the account with these BACS
+    /// numbers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bacs: Option<RecipientBACS<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<PaymentScheme>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentScheme {
+    LocalDefault,
+    LocalInstant,
+    SepaCreditTransfer,
+    SepaCreditTransferInstant,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentSchedule<T: AsRef<str>> {
+    pub interval: PaymentScheduleInterval,
+    /// The day of the interval on which to schedule the payment. Weekly
+    /// payments use 1-7 (Monday-Sunday) and monthly payments use 1-28, or
+    /// negative values to count back from the end of the month.
+    pub interval_execution_day: i32,
+    /// A date in ISO 8601 format (YYYY-MM-DD).
+    pub start_date: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjusted_start_date: Option<T>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentScheduleInterval {
+    Weekly,
+    Monthly,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreatePaymentRequest<T> {
+    type Response = CreatePaymentResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/payment/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreatePaymentResponse {
+    pub payment_id: String,
+    pub status: PaymentStatus,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+pub enum PaymentStatus {
+    #[serde(rename = "PAYMENT_STATUS_INPUT_NEEDED")]
+    InputNeeded,
+    #[serde(rename = "PAYMENT_STATUS_PROCESSING")]
+    Processing,
+    #[serde(rename = "PAYMENT_STATUS_INITIATED")]
+    Initiated,
+    #[serde(rename = "PAYMENT_STATUS_COMPLETED")]
+    Completed,
+    #[serde(rename = "PAYMENT_STATUS_INSUFFICIENT_FUNDS")]
+    InsufficientFunds,
+    #[serde(rename = "PAYMENT_STATUS_FAILED")]
+    Failed,
+    #[serde(rename = "PAYMENT_STATUS_BLOCKED")]
+    Blocked,
+    #[serde(rename = "PAYMENT_STATUS_EXECUTED")]
+    Executed,
+    #[serde(rename = "PAYMENT_STATUS_SETTLED")]
+    Settled,
+    #[serde(rename = "PAYMENT_STATUS_AUTHORISING")]
+    Authorising,
+    #[serde(rename = "PAYMENT_STATUS_CANCELLED")]
+    Cancelled,
+    #[serde(rename = "PAYMENT_STATUS_ESTABLISHED")]
+    Established,
+    #[serde(rename = "PAYMENT_STATUS_REJECTED")]
+    Rejected,
+    /// The status is unknown, also used for statuses introduced after this
+    /// version of the crate.
+    #[serde(rename = "PAYMENT_STATUS_UNKNOWN")]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetPaymentRequest<T: AsRef<str>> {
+    pub payment_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetPaymentRequest<T> {
+    type Response = GetPaymentResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/payment/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetPaymentResponse {
+    #[serde(flatten)]
+    pub payment: Payment,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Payment {
+    pub payment_id: String,
+    pub amount: PaymentAmount,
+    pub status: PaymentStatus,
+    pub recipient_id: String,
+    pub reference: String,
+    pub adjusted_reference: Option<String>,
+    /// The date and time of the last time the status was updated, in ISO 8601
+    /// format.
+    pub last_status_update: String,
+    pub schedule: Option<PaymentSchedule<String>>,
+    pub refund_details: Option<RefundDetails>,
+    pub bacs: Option<RecipientBACS<String>>,
+    pub iban: Option<String>,
+    pub refund_ids: Option<Vec<String>>,
+    pub amount_refunded: Option<PaymentAmount>,
+    pub wallet_id: Option<String>,
+    pub scheme: Option<PaymentScheme>,
+    pub consent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RefundDetails {
+    pub name: String,
+    pub iban: Option<String>,
+    pub bacs: Option<RecipientBACS<String>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct ListPaymentsRequest<T: AsRef<str>> {
+    /// The maximum number of payments to return, defaults to 10 and must be
+    /// between 1 and 200.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+    /// A date-time string in RFC 3339 format, only payments created before
+    /// the cursor will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent_id: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for ListPaymentsRequest<T> {
+    type Response = ListPaymentsResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/payment/list".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListPaymentsResponse {
+    pub payments: Vec<Payment>,
+    /// Cursor for the next page of results, none if there are no more
+    /// payments.
+    pub next_cursor: Option<String>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReversePaymentRequest<T: AsRef<str>> {
+    pub payment_id: T,
+    /// A random key provided by the client, per unique reversal. Maximum of
+    /// 128 characters.
+    pub idempotency_key: T,
+    pub reference: T,
+    /// The amount to refund, defaults to the full payment amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<PaymentAmount>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for ReversePaymentRequest<T> {
+    type Response = ReversePaymentResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/payment/reverse".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReversePaymentResponse {
+    pub refund_id: String,
+    /// Status of the refund, one of the `WALLET_TRANSACTION_STATUS_*` values.
+    pub status: String,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatePaymentConsentRequest<'a, T: AsRef<str>> {
+    pub recipient_id: T,
+    pub reference: T,
+    pub scopes: &'a [PaymentConsentScope],
+    pub constraints: PaymentConsentConstraints<'a, T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreatePaymentConsentRequest<'_, T> {
+    type Response = CreatePaymentConsentResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/consent/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentConsentScope {
+    MeToMe,
+    External,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaymentConsentConstraints<'a, T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_date_time: Option<PaymentConsentValidDateTime<T>>,
+    /// Maximum amount of a single payment initiated using the consent.
+    pub max_payment_amount: PaymentAmount,
+    /// Maximum cumulative amounts for the consent over each period.
+    pub periodic_amounts: &'a [PaymentConsentPeriodicAmount],
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentConsentValidDateTime<T: AsRef<str>> {
+    /// The date and time from which the consent is valid, in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<T>,
+    /// The date and time at which the consent expires, in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<T>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct PaymentConsentPeriodicAmount {
+    pub amount: PaymentAmount,
+    pub interval: PaymentConsentInterval,
+    pub alignment: PaymentConsentAlignment,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentConsentInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentConsentAlignment {
+    /// Periods are aligned to calendar boundaries (e.g. the start of a month).
+    Calendar,
+    /// Periods are aligned to the date the consent was created.
+    Consent,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreatePaymentConsentResponse {
+    pub consent_id: String,
+    pub status: PaymentConsentStatus,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentConsentStatus {
+    Unauthorised,
+    Authorised,
+    Revoked,
+    Rejected,
+    Expired,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetPaymentConsentRequest<T: AsRef<str>> {
+    pub consent_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetPaymentConsentRequest<T> {
+    type Response = GetPaymentConsentResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/consent/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetPaymentConsentResponse {
+    #[serde(flatten)]
+    pub consent: PaymentConsent,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaymentConsent {
+    pub consent_id: String,
+    pub status: PaymentConsentStatus,
+    /// Consent creation timestamp, in ISO 8601 format.
+    pub created_at: String,
+    pub recipient_id: String,
+    pub reference: String,
+    pub constraints: PaymentConsentConstraintsResponse,
+    pub scopes: Vec<PaymentConsentScope>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaymentConsentConstraintsResponse {
+    pub valid_date_time: Option<PaymentConsentValidDateTime<String>>,
+    pub max_payment_amount: PaymentAmount,
+    pub periodic_amounts: Vec<PaymentConsentPeriodicAmount>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RevokePaymentConsentRequest<T: AsRef<str>> {
+    pub consent_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for RevokePaymentConsentRequest<T> {
+    type Response = RevokePaymentConsentResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/consent/revoke".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevokePaymentConsentResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExecutePaymentConsentRequest<T: AsRef<str>> {
+    pub consent_id: T,
+    pub amount: PaymentAmount,
+    /// A random key provided by the client, per unique consent payment.
+    /// Maximum of 128 characters.
+    pub idempotency_key: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for ExecutePaymentConsentRequest<T> {
+    type Response = ExecutePaymentConsentResponse;
+
+    fn path(&self) -> String {
+        "/payment_initiation/consent/payment/execute".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExecutePaymentConsentResponse {
+    pub payment_id: String,
+    pub status: PaymentStatus,
+    pub request_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unknown_payment_statuses() {
+        for status in ["PAYMENT_STATUS_UNKNOWN", "PAYMENT_STATUS_PAUSED"] {
+            let parsed: PaymentStatus = serde_json::from_value(status.into()).unwrap();
+            assert_eq!(parsed, PaymentStatus::Unknown);
+        }
+        assert_eq!(
+            serde_json::to_value(PaymentStatus::Unknown).unwrap(),
+            "PAYMENT_STATUS_UNKNOWN"
+        );
+    }
+}
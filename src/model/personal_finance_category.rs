@@ -0,0 +1,372 @@
+use super::*;
+
+/// Plaid's personal finance category (PFC) for a transaction.
+///
+/// https://plaid.com/docs/transactions/pfc-migration/
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq)]
+pub struct PersonalFinanceCategory {
+    pub primary: PersonalFinanceCategoryPrimary,
+    pub detailed: PersonalFinanceCategoryDetailed,
+    /// Plaid's confidence in the categorization, may be missing for older
+    /// transactions.
+    pub confidence_level: Option<ConfidenceLevel>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConfidenceLevel {
+    /// More than 98% confidence.
+    VeryHigh,
+    /// More than 90% confidence.
+    High,
+    /// Moderate confidence.
+    Medium,
+    /// The categorization may be inaccurate.
+    Low,
+    /// Plaid could not determine a confidence level.
+    Unknown,
+}
+
+/// An entry of the personal finance category taxonomy.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PersonalFinanceCategoryEntry {
+    pub primary: PersonalFinanceCategoryPrimary,
+    pub detailed: PersonalFinanceCategoryDetailed,
+    /// A human readable name for the detailed category, e.g. "Coffee".
+    pub display_name: &'static str,
+    /// Plaid's description of the detailed category.
+    pub description: &'static str,
+}
+
+macro_rules! personal_finance_categories {
+    ($(
+        $primary:ident => $primary_name:literal {
+            $($detailed:ident => $detailed_name:literal, $description:literal;)+
+        }
+    )+) => {
+        /// High level personal finance category. New categories introduced
+        /// by Plaid are parsed as `Unknown`.
+        #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Hash, Clone, Copy)]
+        #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+        pub enum PersonalFinanceCategoryPrimary {
+            $($primary,)+
+            #[serde(other)]
+            Unknown,
+        }
+
+        /// Granular personal finance category, each belongs to a single
+        /// primary category. New categories introduced by Plaid are parsed
+        /// as `Unknown`.
+        #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Hash, Clone, Copy)]
+        #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+        pub enum PersonalFinanceCategoryDetailed {
+            $($($detailed,)+)+
+            #[serde(other)]
+            Unknown,
+        }
+
+        /// The personal finance category taxonomy, in the order published by
+        /// Plaid.
+        pub static PERSONAL_FINANCE_CATEGORY_TAXONOMY: &[PersonalFinanceCategoryEntry] = &[
+            $($(PersonalFinanceCategoryEntry {
+                primary: PersonalFinanceCategoryPrimary::$primary,
+                detailed: PersonalFinanceCategoryDetailed::$detailed,
+                display_name: $detailed_name,
+                description: $description,
+            },)+)+
+        ];
+
+        impl PersonalFinanceCategoryPrimary {
+            /// A human readable name for the category, e.g. "Food and Drink".
+            pub fn display_name(&self) -> &'static str {
+                match self {
+                    $(Self::$primary => $primary_name,)+
+                    Self::Unknown => "Unknown",
+                }
+            }
+        }
+
+        impl PersonalFinanceCategoryDetailed {
+            /// The primary category this category belongs to.
+            pub fn primary(&self) -> PersonalFinanceCategoryPrimary {
+                match self {
+                    $($(Self::$detailed => PersonalFinanceCategoryPrimary::$primary,)+)+
+                    Self::Unknown => PersonalFinanceCategoryPrimary::Unknown,
+                }
+            }
+
+            /// The taxonomy entry for the category, none if the category is
+            /// unknown.
+            pub fn entry(&self) -> Option<&'static PersonalFinanceCategoryEntry> {
+                PERSONAL_FINANCE_CATEGORY_TAXONOMY
+                    .iter()
+                    .find(|entry| entry.detailed == *self)
+            }
+
+            /// A human readable name for the category, e.g. "Coffee".
+            pub fn display_name(&self) -> &'static str {
+                self.entry().map_or("Unknown", |entry| entry.display_name)
+            }
+
+            /// Plaid's description of the category.
+            pub fn description(&self) -> &'static str {
+                self.entry().map_or("", |entry| entry.description)
+            }
+        }
+    };
+}
+
+personal_finance_categories! {
+    Income => "Income" {
+        IncomeDividends => "Dividends", "Dividends from investment accounts";
+        IncomeInterestEarned => "Interest Earned", "Income from interest on savings accounts";
+        IncomeRetirementPension => "Retirement Pension", "Income from pension payments";
+        IncomeTaxRefund => "Tax Refund", "Income from tax refunds";
+        IncomeUnemployment => "Unemployment", "Income from unemployment benefits, including unemployment insurance and healthcare";
+        IncomeWages => "Wages", "Income from salaries, gig-economy work, and tips earned";
+        IncomeOtherIncome => "Other Income", "Other miscellaneous income, including alimony, social security, child support, and rental";
+    }
+    TransferIn => "Transfer In" {
+        TransferInCashAdvancesAndLoans => "Cash Advances and Loans", "Loans and cash advances deposited into a bank account";
+        TransferInDeposit => "Deposit", "Cash, checks, and ATM deposits into a bank account";
+        TransferInInvestmentAndRetirementFunds => "Investment and Retirement Funds", "Inbound transfers to an investment or retirement account";
+        TransferInSavings => "Savings", "Inbound transfers to a savings account";
+        TransferInAccountTransfer => "Account Transfer", "General inbound transfers from another account";
+        TransferInOtherTransferIn => "Other Transfer In", "Other miscellaneous inbound transactions";
+    }
+    TransferOut => "Transfer Out" {
+        TransferOutInvestmentAndRetirementFunds => "Investment and Retirement Funds", "Transfers to an investment or retirement account, including investment apps such as Acorns, Betterment";
+        TransferOutSavings => "Savings", "Outbound transfers to savings accounts";
+        TransferOutWithdrawal => "Withdrawal", "Withdrawals from a bank account";
+        TransferOutAccountTransfer => "Account Transfer", "General outbound transfers to another account";
+        TransferOutOtherTransferOut => "Other Transfer Out", "Other miscellaneous outbound transactions";
+    }
+    LoanPayments => "Loan Payments" {
+        LoanPaymentsCarPayment => "Car Payment", "Car loans and leases";
+        LoanPaymentsCreditCardPayment => "Credit Card Payment", "Payments to a credit card. These are positive amounts for credit card accounts and negative amounts for depository accounts";
+        LoanPaymentsPersonalLoanPayment => "Personal Loan Payment", "Personal loans, including cash advances and buy now pay later repayments";
+        LoanPaymentsMortgagePayment => "Mortgage Payment", "Payments on mortgages";
+        LoanPaymentsStudentLoanPayment => "Student Loan Payment", "Payments on student loans. For college tuition, refer to \"General Services - Education\"";
+        LoanPaymentsOtherPayment => "Other Payment", "Other miscellaneous debt payments";
+    }
+    BankFees => "Bank Fees" {
+        BankFeesAtmFees => "ATM Fees", "Fees incurred for out-of-network ATMs";
+        BankFeesForeignTransactionFees => "Foreign Transaction Fees", "Fees incurred on non-domestic transactions";
+        BankFeesInsufficientFunds => "Insufficient Funds", "Fees relating to insufficient funds";
+        BankFeesInterestCharge => "Interest Charge", "Fees incurred for interest on purchases, including not-paid-in-full or interest on cash advances";
+        BankFeesOverdraftFees => "Overdraft Fees", "Fees incurred when an account is in overdraft";
+        BankFeesOtherBankFees => "Other Bank Fees", "Other miscellaneous bank fees";
+    }
+    Entertainment => "Entertainment" {
+        EntertainmentCasinosAndGambling => "Casinos and Gambling", "Gambling, casinos, and sports betting";
+        EntertainmentMusicAndAudio => "Music and Audio", "Digital and in-person music purchases, including music streaming services";
+        EntertainmentSportingEventsAmusementParksAndMuseums => "Sporting Events, Amusement Parks and Museums", "Purchases made at sporting events, music venues, concerts, museums, and amusement parks";
+        EntertainmentTvAndMovies => "TV and Movies", "In home movie streaming services and movie theaters";
+        EntertainmentVideoGames => "Video Games", "Digital and in-person video game purchases";
+        EntertainmentOtherEntertainment => "Other Entertainment", "Other miscellaneous entertainment purchases, including night life and adult entertainment";
+    }
+    FoodAndDrink => "Food and Drink" {
+        FoodAndDrinkBeerWineAndLiquor => "Beer, Wine and Liquor", "Beer, Wine & Liquor Stores";
+        FoodAndDrinkCoffee => "Coffee", "Purchases at coffee shops or cafes";
+        FoodAndDrinkFastFood => "Fast Food", "Dining expenses for fast food chains";
+        FoodAndDrinkGroceries => "Groceries", "Purchases for fresh produce and groceries, including farmers' markets";
+        FoodAndDrinkRestaurant => "Restaurant", "Dining expenses for restaurants, bars, gastropubs, and diners";
+        FoodAndDrinkVendingMachines => "Vending Machines", "Purchases made at vending machine operators";
+        FoodAndDrinkOtherFoodAndDrink => "Other Food and Drink", "Other miscellaneous food and drink, including desserts, juice bars, and delis";
+    }
+    GeneralMerchandise => "General Merchandise" {
+        GeneralMerchandiseBookstoresAndNewsstands => "Bookstores and Newsstands", "Books, magazines, and news";
+        GeneralMerchandiseClothingAndAccessories => "Clothing and Accessories", "Apparel, shoes, and jewelry";
+        GeneralMerchandiseConvenienceStores => "Convenience Stores", "Purchases at convenience stores";
+        GeneralMerchandiseDepartmentStores => "Department Stores", "Retail stores with wide ranges of consumer goods, typically specializing in clothing and home goods";
+        GeneralMerchandiseDiscountStores => "Discount Stores", "Stores selling goods at a discounted price";
+        GeneralMerchandiseElectronics => "Electronics", "Electronics stores and websites";
+        GeneralMerchandiseGiftsAndNovelties => "Gifts and Novelties", "Photo, gifts, cards, and floral stores";
+        GeneralMerchandiseOfficeSupplies => "Office Supplies", "Stores that specialize in office goods";
+        GeneralMerchandiseOnlineMarketplaces => "Online Marketplaces", "Multi-purpose e-commerce platforms such as Etsy, Ebay and Amazon";
+        GeneralMerchandisePetSupplies => "Pet Supplies", "Pet supplies and pet food";
+        GeneralMerchandiseSportingGoods => "Sporting Goods", "Sporting goods, camping gear, and outdoor equipment";
+        GeneralMerchandiseSuperstores => "Superstores", "Superstores such as Target and Walmart, selling both groceries and general merchandise";
+        GeneralMerchandiseTobaccoAndVape => "Tobacco and Vape", "Purchases for tobacco and vaping products";
+        GeneralMerchandiseOtherGeneralMerchandise => "Other General Merchandise", "Other miscellaneous merchandise, including toys, hobbies, and arts and crafts";
+    }
+    HomeImprovement => "Home Improvement" {
+        HomeImprovementFurniture => "Furniture", "Furniture, bedding, and home accessories";
+        HomeImprovementHardware => "Hardware", "Building materials, hardware stores, paint, and wallpaper";
+        HomeImprovementRepairAndMaintenance => "Repair and Maintenance", "Plumbing, lighting, gardening, and roofing";
+        HomeImprovementSecurity => "Security", "Home security system purchases";
+        HomeImprovementOtherHomeImprovement => "Other Home Improvement", "Other miscellaneous home purchases, including pool installation and pest control";
+    }
+    Medical => "Medical" {
+        MedicalDentalCare => "Dental Care", "Dentists and general dental care";
+        MedicalEyeCare => "Eye Care", "Optometrists, contacts, and glasses stores";
+        MedicalNursingCare => "Nursing Care", "Nursing care and facilities";
+        MedicalPharmaciesAndSupplements => "Pharmacies and Supplements", "Pharmacies and nutrition shops";
+        MedicalPrimaryCare => "Primary Care", "Doctors and physicians";
+        MedicalVeterinaryServices => "Veterinary Services", "Prevention and care procedures for animals";
+        MedicalOtherMedical => "Other Medical", "Other miscellaneous medical, including blood work, hospitals, and ambulances";
+    }
+    PersonalCare => "Personal Care" {
+        PersonalCareGymsAndFitnessCenters => "Gyms and Fitness Centers", "Gyms, fitness centers, and workout classes";
+        PersonalCareHairAndBeauty => "Hair and Beauty", "Manicures, haircuts, waxing, spa/massages, and bath and beauty products";
+        PersonalCareLaundryAndDryCleaning => "Laundry and Dry Cleaning", "Wash and fold, and dry cleaning expenses";
+        PersonalCareOtherPersonalCare => "Other Personal Care", "Other miscellaneous personal care, including mental health apps and services";
+    }
+    GeneralServices => "General Services" {
+        GeneralServicesAccountingAndFinancialPlanning => "Accounting and Financial Planning", "Financial planning, and tax and accounting services";
+        GeneralServicesAutomotive => "Automotive", "Oil changes, car washes, repairs, and towing";
+        GeneralServicesChildcare => "Childcare", "Babysitters and daycare";
+        GeneralServicesConsultingAndLegal => "Consulting and Legal", "Consulting and legal services";
+        GeneralServicesEducation => "Education", "Elementary, high school, professional schools, and college tuition";
+        GeneralServicesInsurance => "Insurance", "Insurance for auto, home, and healthcare";
+        GeneralServicesPostageAndShipping => "Postage and Shipping", "Mail, packaging, and shipping services";
+        GeneralServicesStorage => "Storage", "Storage services and facilities";
+        GeneralServicesOtherGeneralServices => "Other General Services", "Other miscellaneous services, including advertising and cloud storage";
+    }
+    GovernmentAndNonProfit => "Government and Non-Profit" {
+        GovernmentAndNonProfitDonations => "Donations", "Charitable, political, and religious donations";
+        GovernmentAndNonProfitGovernmentDepartmentsAndAgencies => "Government Departments and Agencies", "Government departments and agencies, such as driving licences, and passport renewal";
+        GovernmentAndNonProfitTaxPayment => "Tax Payment", "Tax payments, including income and property taxes";
+        GovernmentAndNonProfitOtherGovernmentAndNonProfit => "Other Government and Non-Profit", "Other miscellaneous government and non-profit agencies";
+    }
+    Transportation => "Transportation" {
+        TransportationBikesAndScooters => "Bikes and Scooters", "Bike and scooter rentals";
+        TransportationGas => "Gas", "Purchases at a gas station";
+        TransportationParking => "Parking", "Parking fees and expenses";
+        TransportationPublicTransit => "Public Transit", "Public transportation, including rail and train, buses, and metro";
+        TransportationTaxisAndRideShares => "Taxis and Ride Shares", "Taxi and ride share services";
+        TransportationTolls => "Tolls", "Toll expenses";
+        TransportationOtherTransportation => "Other Transportation", "Other miscellaneous transportation expenses";
+    }
+    Travel => "Travel" {
+        TravelFlights => "Flights", "Airline expenses";
+        TravelLodging => "Lodging", "Hotels, motels, and hosted accommodation such as Airbnb";
+        TravelRentalCars => "Rental Cars", "Rental cars, charter buses, and trucks";
+        TravelOtherTravel => "Other Travel", "Other miscellaneous travel expenses";
+    }
+    RentAndUtilities => "Rent and Utilities" {
+        RentAndUtilitiesGasAndElectricity => "Gas and Electricity", "Gas and electricity bills";
+        RentAndUtilitiesInternetAndCable => "Internet and Cable", "Internet and cable bills";
+        RentAndUtilitiesRent => "Rent", "Rent payment";
+        RentAndUtilitiesSewageAndWasteManagement => "Sewage and Waste Management", "Sewage and garbage disposal bills";
+        RentAndUtilitiesTelephone => "Telephone", "Cell phone bills";
+        RentAndUtilitiesWater => "Water", "Water bills";
+        RentAndUtilitiesOtherUtilities => "Other Utilities", "Other miscellaneous utility bills";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PersonalFinanceCategoryDetailed as Detailed;
+    use PersonalFinanceCategoryPrimary as Primary;
+
+    #[test]
+    fn detailed_codes_round_trip() {
+        for (detailed, code) in [
+            (Detailed::FoodAndDrinkCoffee, "FOOD_AND_DRINK_COFFEE"),
+            (Detailed::BankFeesAtmFees, "BANK_FEES_ATM_FEES"),
+            (
+                Detailed::TransferInCashAdvancesAndLoans,
+                "TRANSFER_IN_CASH_ADVANCES_AND_LOANS",
+            ),
+            (
+                Detailed::RentAndUtilitiesGasAndElectricity,
+                "RENT_AND_UTILITIES_GAS_AND_ELECTRICITY",
+            ),
+        ] {
+            let json = serde_json::to_value(detailed).unwrap();
+            assert_eq!(json, code);
+            assert_eq!(serde_json::from_value::<Detailed>(json).unwrap(), detailed);
+        }
+    }
+
+    #[test]
+    fn detailed_categories_map_to_their_primary() {
+        for (detailed, primary) in [
+            (Detailed::IncomeDividends, Primary::Income),
+            (
+                Detailed::TransferInCashAdvancesAndLoans,
+                Primary::TransferIn,
+            ),
+            (
+                Detailed::TransferOutInvestmentAndRetirementFunds,
+                Primary::TransferOut,
+            ),
+            (Detailed::LoanPaymentsCarPayment, Primary::LoanPayments),
+            (Detailed::BankFeesAtmFees, Primary::BankFees),
+            (
+                Detailed::EntertainmentCasinosAndGambling,
+                Primary::Entertainment,
+            ),
+            (
+                Detailed::FoodAndDrinkBeerWineAndLiquor,
+                Primary::FoodAndDrink,
+            ),
+            (
+                Detailed::GeneralMerchandiseBookstoresAndNewsstands,
+                Primary::GeneralMerchandise,
+            ),
+            (Detailed::HomeImprovementFurniture, Primary::HomeImprovement),
+            (Detailed::MedicalDentalCare, Primary::Medical),
+            (
+                Detailed::PersonalCareGymsAndFitnessCenters,
+                Primary::PersonalCare,
+            ),
+            (
+                Detailed::GeneralServicesAccountingAndFinancialPlanning,
+                Primary::GeneralServices,
+            ),
+            (
+                Detailed::GovernmentAndNonProfitDonations,
+                Primary::GovernmentAndNonProfit,
+            ),
+            (
+                Detailed::TransportationBikesAndScooters,
+                Primary::Transportation,
+            ),
+            (Detailed::TravelFlights, Primary::Travel),
+            (
+                Detailed::RentAndUtilitiesGasAndElectricity,
+                Primary::RentAndUtilities,
+            ),
+            (Detailed::Unknown, Primary::Unknown),
+        ] {
+            assert_eq!(detailed.primary(), primary);
+        }
+
+        for entry in PERSONAL_FINANCE_CATEGORY_TAXONOMY {
+            assert_eq!(entry.detailed.primary(), entry.primary);
+            assert_eq!(entry.detailed.entry(), Some(entry));
+        }
+    }
+
+    #[test]
+    fn unknown_codes_fall_back() {
+        let category: PersonalFinanceCategory = serde_json::from_str(
+            r#"{"primary": "SPACE_TRAVEL", "detailed": "SPACE_TRAVEL_ROCKETS", "confidence_level": "HIGH"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(category.primary, Primary::Unknown);
+        assert_eq!(category.detailed, Detailed::Unknown);
+        assert_eq!(category.detailed.entry(), None);
+        assert_eq!(category.detailed.display_name(), "Unknown");
+        assert_eq!(category.detailed.description(), "");
+        assert_eq!(category.primary.display_name(), "Unknown");
+    }
+
+    #[test]
+    fn display_names() {
+        assert_eq!(Primary::FoodAndDrink.display_name(), "Food and Drink");
+        assert_eq!(Detailed::FoodAndDrinkCoffee.display_name(), "Coffee");
+        assert_eq!(
+            Detailed::FoodAndDrinkCoffee.description(),
+            "Purchases at coffee shops or cafes"
+        );
+        assert_eq!(
+            PERSONAL_FINANCE_CATEGORY_TAXONOMY[0].detailed,
+            Detailed::IncomeDividends
+        );
+    }
+}
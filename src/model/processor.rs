@@ -0,0 +1,173 @@
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct CreateProcessorTokenRequest<T: AsRef<str>> {
+    pub access_token: T,
+    pub account_id: T,
+    /// The processor to create a token for (e.g. dwolla, galileo,
+    /// modern_treasury). Stripe tokens are created with
+    /// `CreateStripeBankAccountTokenRequest` instead.
+    pub processor: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateProcessorTokenRequest<T> {
+    type Response = CreateProcessorTokenResponse;
+
+    fn path(&self) -> String {
+        "/processor/token/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateProcessorTokenResponse {
+    pub processor_token: String,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateStripeBankAccountTokenRequest<T: AsRef<str>> {
+    pub access_token: T,
+    pub account_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateStripeBankAccountTokenRequest<T> {
+    type Response = CreateStripeBankAccountTokenResponse;
+
+    fn path(&self) -> String {
+        "/processor/stripe/bank_account_token/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateStripeBankAccountTokenResponse {
+    pub stripe_bank_account_token: String,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetProcessorTokenPermissionsRequest<'a, T: AsRef<str>> {
+    pub processor_token: T,
+    /// Products the processor is allowed to access, an empty list grants
+    /// access to all products the Item is initialized with.
+    pub products: &'a [T],
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for SetProcessorTokenPermissionsRequest<'_, T> {
+    type Response = SetProcessorTokenPermissionsResponse;
+
+    fn path(&self) -> String {
+        "/processor/token/permissions/set".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SetProcessorTokenPermissionsResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetProcessorTokenPermissionsRequest<T: AsRef<str>> {
+    pub processor_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetProcessorTokenPermissionsRequest<T> {
+    type Response = GetProcessorTokenPermissionsResponse;
+
+    fn path(&self) -> String {
+        "/processor/token/permissions/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetProcessorTokenPermissionsResponse {
+    pub products: Vec<String>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetProcessorAuthRequest<T: AsRef<str>> {
+    pub processor_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetProcessorAuthRequest<T> {
+    type Response = GetProcessorAuthResponse;
+
+    fn path(&self) -> String {
+        "/processor/auth/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetProcessorAuthResponse {
+    pub account: Account,
+    pub numbers: ProcessorAccountNumbers,
+    pub request_id: String,
+}
+
+/// Account numbers for the single account a processor token is scoped to.
+/// At most one set of numbers is returned, depending on the account's
+/// country.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProcessorAccountNumbers {
+    pub ach: Option<ACHAccountNumber>,
+    pub eft: Option<EFTAccountNumber>,
+    pub international: Option<InternationalAccountNumber>,
+    pub bacs: Option<BACSAccountNumber>,
+}
+
+impl From<ProcessorAccountNumbers> for AccountNumbers {
+    fn from(numbers: ProcessorAccountNumbers) -> Self {
+        Self {
+            ach: numbers.ach.into_iter().collect(),
+            eft: numbers.eft.into_iter().collect(),
+            international: numbers.international.into_iter().collect(),
+            bacs: numbers.bacs.into_iter().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetProcessorBalanceRequest<T: AsRef<str>> {
+    pub processor_token: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<ProcessorBalanceFilter<T>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProcessorBalanceFilter<T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_last_updated_datetime: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetProcessorBalanceRequest<T> {
+    type Response = GetProcessorBalanceResponse;
+
+    fn path(&self) -> String {
+        "/processor/balance/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetProcessorBalanceResponse {
+    pub account: Account,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetProcessorIdentityRequest<T: AsRef<str>> {
+    pub processor_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetProcessorIdentityRequest<T> {
+    type Response = GetProcessorIdentityResponse;
+
+    fn path(&self) -> String {
+        "/processor/identity/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetProcessorIdentityResponse {
+    pub account: AccountIdentity,
+    pub request_id: String,
+}
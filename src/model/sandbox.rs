@@ -13,15 +13,47 @@ pub struct CreatePublicTokenOptions<T: AsRef<str>> {
     pub webhook: Option<T>,
     /// Default username is "user_good".
     pub override_username: Option<T>,
-    /// Default password is "pass_good".
+    /// Default password is "pass_good". For `SANDBOX_CUSTOM_USERNAME` this is
+    /// the JSON from `SandboxUserConfig::to_password`.
     pub override_password: Option<T>,
     pub transactions: Option<CreatePublicTokenOptionsTransactions<T>>,
+    /// Required when `initial_products` includes "income_verification".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub income_verification: Option<CreatePublicTokenOptionsIncomeVerification>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CreatePublicTokenOptionsTransactions<T: AsRef<str>> {
     pub start_date: Option<T>,
     pub end_date: Option<T>,
+    /// Number of days of transaction history to request, between 1 and 730.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_requested: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct CreatePublicTokenOptionsIncomeVerification {
+    pub income_source_types: Vec<IncomeSourceType>,
+    /// Required when `income_source_types` includes `Bank`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_income: Option<LinkBankIncome>,
+}
+
+impl CreatePublicTokenOptionsIncomeVerification {
+    /// Creates the Item for bank income with the given history length.
+    pub fn bank_income(days_requested: u32) -> Self {
+        Self {
+            income_source_types: vec![IncomeSourceType::Bank],
+            bank_income: Some(LinkBankIncome { days_requested }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum IncomeSourceType {
+    Bank,
+    Payroll,
 }
 
 impl<T: AsRef<str> + serde::Serialize> Endpoint for CreatePublicTokenRequest<'_, T> {
@@ -53,13 +85,14 @@ impl<T: AsRef<str> + serde::Serialize> Endpoint for ResetLoginRequest<T> {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResetLoginResponse {
     pub reset_login: bool,
+    pub request_id: String,
 }
 
 #[derive(Debug, Serialize)]
 pub struct SetVerificationStatusRequest<T: AsRef<str>> {
     pub access_token: T,
     pub account_id: T,
-    /// One of automatically_verified or verification_required
+    /// One of automatically_verified or verification_expired.
     pub verification_status: T,
 }
 
@@ -73,20 +106,93 @@ impl<T: AsRef<str> + serde::Serialize> Endpoint for SetVerificationStatusRequest
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetVerificationStatusResponse {
-    pub reset_login: bool,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SelectOAuthAccountsRequest<'a, T: AsRef<str>> {
+    /// The `oauth_state_id` from Link's OAuth redirect URI.
+    pub oauth_state_id: T,
+    /// Account IDs of the sandbox OAuth institution to share with the Item.
+    pub accounts: &'a [T],
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for SelectOAuthAccountsRequest<'_, T> {
+    type Response = SelectOAuthAccountsResponse;
+
+    fn path(&self) -> String {
+        "/sandbox/oauth/select_accounts".into()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SelectOAuthAccountsResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateSandboxProcessorTokenRequest<T: AsRef<str>> {
+    pub institution_id: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<CreateSandboxProcessorTokenOptions<T>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateSandboxProcessorTokenOptions<T: AsRef<str>> {
+    /// Default username is "user_good".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_username: Option<T>,
+    /// Default password is "pass_good".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_password: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateSandboxProcessorTokenRequest<T> {
+    type Response = CreateProcessorTokenResponse;
+
+    fn path(&self) -> String {
+        "/sandbox/processor_token/create".into()
+    }
 }
 
 #[derive(Debug, Serialize)]
 pub struct FireWebhookRequest<T: AsRef<str>> {
     pub access_token: T,
-    /// One of DEFAULT_UPDATE.
+    /// The type of webhook to fire, defaults to TRANSACTIONS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_type: Option<WebhookType>,
     pub webhook_code: WebhookCode,
 }
 
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WebhookType {
+    Auth,
+    Holdings,
+    InvestmentsTransactions,
+    Item,
+    Liabilities,
+    Transactions,
+}
+
+/// Webhook codes that can be fired in the sandbox. Each code is only valid
+/// for some webhook types, e.g. `SyncUpdatesAvailable` for TRANSACTIONS and
+/// `LoginRepaired` for ITEM.
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WebhookCode {
-    #[serde(rename = "DEFAULT_UPDATE")]
     DefaultUpdate,
+    NewAccountsAvailable,
+    SmsMicrodepositsVerification,
+    AuthDataUpdate,
+    LoginRepaired,
+    RecurringTransactionsUpdate,
+    SyncUpdatesAvailable,
+    ProductReady,
+    Error,
+    UserPermissionRevoked,
+    UserAccountRevoked,
+    PendingDisconnect,
 }
 
 impl<T: AsRef<str> + serde::Serialize> Endpoint for FireWebhookRequest<T> {
@@ -102,3 +208,364 @@ pub struct FireWebhookResponse {
     pub webhook_fired: bool,
     pub request_id: String,
 }
+
+#[derive(Debug, Serialize)]
+pub struct FireIncomeWebhookRequest<T: AsRef<str>> {
+    /// The Item ID associated with the verification.
+    pub item_id: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<T>,
+    /// The URL to which the webhook should be sent.
+    pub webhook: T,
+    pub verification_status: IncomeVerificationStatus,
+    /// Defaults to INCOME_VERIFICATION.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_code: Option<IncomeWebhookCode>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeVerificationStatus {
+    VerificationStatusProcessingComplete,
+    VerificationStatusProcessingFailed,
+    VerificationStatusPendingApproval,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeWebhookCode {
+    IncomeVerification,
+    IncomeVerificationRiskSignals,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for FireIncomeWebhookRequest<T> {
+    type Response = FireIncomeWebhookResponse;
+
+    fn path(&self) -> String {
+        "/sandbox/income/fire_webhook".into()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FireIncomeWebhookResponse {
+    pub request_id: String,
+}
+
+/// Username that makes `/sandbox/public_token/create` read a
+/// `SandboxUserConfig` from the password.
+pub const SANDBOX_CUSTOM_USERNAME: &str = "user_custom";
+
+/// Configuration for a sandbox `user_custom` Item with fixed accounts,
+/// balances, transactions, identity and liabilities. Pass
+/// `SANDBOX_CUSTOM_USERNAME` and `to_password()` as the override username and
+/// password when creating a public token.
+///
+/// https://plaid.com/docs/sandbox/user-custom/
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct SandboxUserConfig {
+    pub override_accounts: Vec<SandboxAccount>,
+}
+
+impl SandboxUserConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_account(mut self, account: SandboxAccount) -> Self {
+        self.override_accounts.push(account);
+        self
+    }
+
+    /// Serializes the configuration to the JSON expected as the sandbox
+    /// password.
+    pub fn to_password(&self) -> String {
+        serde_json::to_string(self).expect("sandbox user config is valid JSON")
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SandboxAccount {
+    pub r#type: AccountType,
+    /// The account subtype, e.g. "checking" or "credit card".
+    pub subtype: String,
+    /// The current balance of the account, before any transactions.
+    pub starting_balance: f64,
+    /// Overrides the available balance, otherwise it matches the current
+    /// balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_available_balance: Option<f64>,
+    /// ISO 4217 currency code, defaults to "USD".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<SandboxAccountMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numbers: Option<SandboxAccountNumbers>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<SandboxTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<Owner>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liability: Option<SandboxLiability>,
+}
+
+impl SandboxAccount {
+    pub fn new(r#type: AccountType, subtype: &str, starting_balance: f64) -> Self {
+        Self {
+            r#type,
+            subtype: subtype.into(),
+            starting_balance,
+            force_available_balance: None,
+            currency: None,
+            meta: None,
+            numbers: None,
+            transactions: vec![],
+            identity: None,
+            liability: None,
+        }
+    }
+
+    pub fn with_available_balance(mut self, available: f64) -> Self {
+        self.force_available_balance = Some(available);
+        self
+    }
+
+    pub fn with_currency(mut self, currency: &str) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    pub fn with_meta(mut self, meta: SandboxAccountMeta) -> Self {
+        self.meta = Some(meta);
+        self
+    }
+
+    pub fn with_numbers(mut self, numbers: SandboxAccountNumbers) -> Self {
+        self.numbers = Some(numbers);
+        self
+    }
+
+    pub fn with_transaction(mut self, transaction: SandboxTransaction) -> Self {
+        self.transactions.push(transaction);
+        self
+    }
+
+    pub fn with_identity(mut self, identity: Owner) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    pub fn with_liability(mut self, liability: SandboxLiability) -> Self {
+        self.liability = Some(liability);
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct SandboxAccountMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub official_name: Option<String>,
+    /// The credit limit, only used for credit accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<f64>,
+    /// The last 2-4 characters of the account number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask: Option<String>,
+}
+
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct SandboxAccountNumbers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach_routing: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach_wire_routing: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eft_institution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eft_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub international_bic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub international_iban: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bacs_sort_code: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SandboxTransaction {
+    /// The date the transaction was made, in ISO 8601 format (YYYY-MM-DD).
+    pub date_transacted: String,
+    /// The date the transaction posted, in ISO 8601 format (YYYY-MM-DD).
+    pub date_posted: String,
+    /// Positive amounts are debits, negative amounts are credits.
+    pub amount: f64,
+    pub description: String,
+    /// ISO 4217 currency code, defaults to "USD".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+impl SandboxTransaction {
+    pub fn new(date: &str, amount: f64, description: &str) -> Self {
+        Self {
+            date_transacted: date.into(),
+            date_posted: date.into(),
+            amount,
+            description: description.into(),
+            currency: None,
+        }
+    }
+}
+
+/// Liability data for credit or student loan accounts, fields that don't
+/// apply to the liability type are ignored.
+#[derive(Debug, Serialize, Clone)]
+pub struct SandboxLiability {
+    pub r#type: SandboxLiabilityType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase_apr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cash_apr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_transfer_apr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub special_apr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_payment_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_payment_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_overdue: Option<bool>,
+    /// Student loan origination date, in ISO 8601 format (YYYY-MM-DD).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origination_date: Option<String>,
+    /// Original principal of a student loan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nominal_apr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_payoff_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_federal: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repayment_plan_type: Option<String>,
+}
+
+impl SandboxLiability {
+    pub fn new(r#type: SandboxLiabilityType) -> Self {
+        Self {
+            r#type,
+            purchase_apr: None,
+            cash_apr: None,
+            balance_transfer_apr: None,
+            special_apr: None,
+            last_payment_amount: None,
+            minimum_payment_amount: None,
+            is_overdue: None,
+            origination_date: None,
+            principal: None,
+            nominal_apr: None,
+            expected_payoff_date: None,
+            is_federal: None,
+            loan_name: None,
+            repayment_plan_type: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SandboxLiabilityType {
+    Credit,
+    Student,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_bank_income_verification() {
+        let options = CreatePublicTokenOptions {
+            webhook: None,
+            override_username: Some("user_bank_income"),
+            override_password: Some("{}"),
+            transactions: None,
+            income_verification: Some(CreatePublicTokenOptionsIncomeVerification::bank_income(90)),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&options).unwrap()["income_verification"],
+            serde_json::json!({
+                "income_source_types": ["bank"],
+                "bank_income": {"days_requested": 90}
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_custom_user_password() {
+        let password = SandboxUserConfig::new()
+            .with_account(
+                SandboxAccount::new(AccountType::Depository, "checking", 100.0)
+                    .with_meta(SandboxAccountMeta {
+                        name: Some("Fixture Checking".into()),
+                        ..SandboxAccountMeta::default()
+                    })
+                    .with_transaction(SandboxTransaction::new("2022-01-03", 4.5, "Coffee"))
+                    .with_identity(Owner {
+                        names: vec!["Alberta Charleson".into()],
+                        phone_numbers: vec![PhoneNumber {
+                            data: "1112223333".into(),
+                            primary: None,
+                            r#type: None,
+                        }],
+                        emails: vec![],
+                        addresses: vec![OwnerAddress {
+                            data: AddressData {
+                                city: None,
+                                region: None,
+                                street: "2992 Cameron Road".into(),
+                                postal_code: None,
+                                country: Some("US".into()),
+                            },
+                            primary: None,
+                        }],
+                    }),
+            )
+            .to_password();
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&password).unwrap(),
+            serde_json::json!({
+                "override_accounts": [{
+                    "type": "depository",
+                    "subtype": "checking",
+                    "starting_balance": 100.0,
+                    "meta": {"name": "Fixture Checking"},
+                    "transactions": [{
+                        "date_transacted": "2022-01-03",
+                        "date_posted": "2022-01-03",
+                        "amount": 4.5,
+                        "description": "Coffee"
+                    }],
+                    "identity": {
+                        "names": ["Alberta Charleson"],
+                        "phone_numbers": [{"data": "1112223333"}],
+                        "emails": [],
+                        "addresses": [{
+                            "data": {"street": "2992 Cameron Road", "country": "US"}
+                        }]
+                    }
+                }]
+            })
+        );
+    }
+}
@@ -0,0 +1,263 @@
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct SignalEvaluateRequest<T: AsRef<str>> {
+    pub access_token: T,
+    /// The account the ACH debit will be initiated from.
+    pub account_id: T,
+    /// A unique ID for the transaction, used to report the decision and any
+    /// return for it later.
+    pub client_transaction_id: T,
+    /// The transaction amount in USD, e.g. 102.05.
+    #[cfg(not(feature = "decimal"))]
+    pub amount: f64,
+    #[cfg(feature = "decimal")]
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    /// True if the end user is present while initiating the ACH transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_present: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_user_id: Option<T>,
+    /// True if the ACH transaction is a recurring transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_recurring: Option<bool>,
+    /// The default ACH or non-ACH payment method to complete the transaction,
+    /// e.g. SAME_DAY_ACH or DEBIT_CARD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<SignalUser<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<SignalDevice<T>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct SignalUser<T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<SignalPersonName<T>>,
+    /// Phone number in E.164 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<SignalAddress<T>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct SignalPersonName<T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<T>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct SignalAddress<T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<T>,
+    /// ISO 3166-1 alpha-2 country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<T>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct SignalDevice<T: AsRef<str>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for SignalEvaluateRequest<T> {
+    type Response = SignalEvaluateResponse;
+
+    fn path(&self) -> String {
+        "/signal/evaluate".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignalEvaluateResponse {
+    pub scores: SignalScores,
+    pub core_attributes: Option<SignalCoreAttributes>,
+    #[serde(default)]
+    pub warnings: Vec<SignalWarning>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct SignalScores {
+    /// Risk of the transaction being returned for reasons initiated by the
+    /// customer, e.g. R05 (unauthorized) or R10 (not authorized by customer).
+    pub customer_initiated_return_risk: Option<SignalScore>,
+    /// Risk of the transaction being returned for reasons initiated by the
+    /// bank, e.g. R01 (insufficient funds) or R02 (account closed).
+    pub bank_initiated_return_risk: Option<SignalScore>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct SignalScore {
+    /// A score from 1 to 99, where higher values indicate a higher risk of
+    /// return.
+    pub score: u8,
+    /// A tier from 1 (lowest risk) to 5 for customer initiated returns or 8
+    /// for bank initiated returns.
+    pub risk_tier: u8,
+}
+
+/// A subset of the facts used by Signal to compute risk scores. Attributes
+/// that could not be computed for an account are `None`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignalCoreAttributes {
+    pub unauthorized_transactions_count_7d: Option<u32>,
+    pub unauthorized_transactions_count_30d: Option<u32>,
+    pub unauthorized_transactions_count_60d: Option<u32>,
+    pub unauthorized_transactions_count_90d: Option<u32>,
+    pub nsf_overdraft_transactions_count_7d: Option<u32>,
+    pub nsf_overdraft_transactions_count_30d: Option<u32>,
+    pub nsf_overdraft_transactions_count_60d: Option<u32>,
+    pub nsf_overdraft_transactions_count_90d: Option<u32>,
+    pub days_since_first_plaid_connection: Option<u32>,
+    pub plaid_connections_count_7d: Option<u32>,
+    pub plaid_connections_count_30d: Option<u32>,
+    pub total_plaid_connections_count: Option<u32>,
+    pub is_savings_or_money_market_account: Option<bool>,
+    pub days_with_negative_balance_count_90d: Option<u32>,
+    pub days_since_account_opening: Option<u32>,
+    #[cfg(not(feature = "decimal"))]
+    pub available_balance: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub available_balance: Option<Decimal>,
+    #[cfg(not(feature = "decimal"))]
+    pub current_balance: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub current_balance: Option<Decimal>,
+    /// Timestamp of the last balance update, in ISO 8601 format.
+    pub balance_last_updated: Option<String>,
+    pub phone_change_count_28d: Option<u32>,
+    pub phone_change_count_90d: Option<u32>,
+    pub email_change_count_28d: Option<u32>,
+    pub email_change_count_90d: Option<u32>,
+    pub address_change_count_28d: Option<u32>,
+    pub address_change_count_90d: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignalWarning {
+    pub warning_type: String,
+    pub warning_code: String,
+    pub warning_message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignalDecisionReportRequest<T: AsRef<str>> {
+    /// Must match the `client_transaction_id` used in `/signal/evaluate`.
+    pub client_transaction_id: T,
+    /// True if the ACH transaction was initiated.
+    pub initiated: bool,
+    /// The number of days the funds were held before being made available to
+    /// the end user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_funds_on_hold: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision_outcome: Option<SignalDecisionOutcome>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<SignalPaymentMethod>,
+    /// The amount made available to the end user before the ACH transaction
+    /// settled.
+    #[cfg(not(feature = "decimal"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_instantly_available: Option<f64>,
+    #[cfg(feature = "decimal")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "rust_decimal::serde::float_option"
+    )]
+    pub amount_instantly_available: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignalDecisionOutcome {
+    Approve,
+    Review,
+    Reject,
+    TakeOtherRiskMeasures,
+    NotEvaluated,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignalPaymentMethod {
+    SameDayAch,
+    NextDayAch,
+    StandardAch,
+    MultiplePaymentMethods,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for SignalDecisionReportRequest<T> {
+    type Response = SignalReportResponse;
+
+    fn path(&self) -> String {
+        "/signal/decision/report".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignalReportResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignalReturnReportRequest<T: AsRef<str>> {
+    /// Must match the `client_transaction_id` used in `/signal/evaluate`.
+    pub client_transaction_id: T,
+    /// The ACH return code, e.g. R01.
+    pub return_code: T,
+    /// Date and time the return was received, in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returned_at: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for SignalReturnReportRequest<T> {
+    type Response = SignalReportResponse;
+
+    fn path(&self) -> String {
+        "/signal/return/report".into()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignalPrepareRequest<T: AsRef<str>> {
+    pub access_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for SignalPrepareRequest<T> {
+    type Response = SignalPrepareResponse;
+
+    fn path(&self) -> String {
+        "/signal/prepare".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignalPrepareResponse {
+    pub request_id: String,
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct ListStatementsRequest<T: AsRef<str>> {
+    pub access_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for ListStatementsRequest<T> {
+    type Response = ListStatementsResponse;
+
+    fn path(&self) -> String {
+        "/statements/list".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListStatementsResponse {
+    pub item_id: String,
+    pub institution_id: String,
+    pub institution_name: String,
+    pub accounts: Vec<StatementAccount>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StatementAccount {
+    pub account_id: String,
+    pub account_mask: Option<String>,
+    pub account_name: String,
+    pub account_official_name: Option<String>,
+    pub account_subtype: Option<String>,
+    pub account_type: AccountType,
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Statement {
+    pub statement_id: String,
+    /// Date the statement was issued, in ISO 8601 format (YYYY-MM-DD).
+    pub date: Option<String>,
+    /// Month of the statement period, 1 through 12.
+    pub month: u8,
+    pub year: u16,
+}
+
+/// Downloads a single statement as a PDF. The response body is not JSON and
+/// is streamed by `Plaid::download_statement` instead of being parsed.
+#[derive(Debug, Serialize)]
+pub struct DownloadStatementRequest<T: AsRef<str>> {
+    pub access_token: T,
+    pub statement_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for DownloadStatementRequest<T> {
+    type Response = ();
+
+    fn path(&self) -> String {
+        "/statements/download".into()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefreshStatementsRequest<T: AsRef<str>> {
+    pub access_token: T,
+    /// A string date with the format YYYY-MM-DD. Start date is inclusive.
+    pub start_date: T,
+    /// A string date with the format YYYY-MM-DD. End date is inclusive.
+    pub end_date: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for RefreshStatementsRequest<T> {
+    type Response = RefreshStatementsResponse;
+
+    fn path(&self) -> String {
+        "/statements/refresh".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RefreshStatementsResponse {
+    pub request_id: String,
+}
@@ -26,8 +26,11 @@ pub struct CreateLinkTokenRequest<'a, T: AsRef<str>> {
     pub language: T,
     pub country_codes: &'a [T],
     pub user: LinkUser<T>,
+    /// Left out when empty, which is only valid in update mode.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
     pub products: &'a [T],
     pub webhook: Option<T>,
+    /// Launches Link in update mode for the Item, `products` may be empty.
     pub access_token: Option<T>,
     pub link_customization_name: Option<T>,
     pub redirect_uri: Option<T>,
@@ -39,6 +42,17 @@ pub struct CreateLinkTokenRequest<'a, T: AsRef<str>> {
     pub income_verification: Option<IncomeVerification<T>>,
     pub auth: Option<LinkAuth<T>>,
     pub institution_id: Option<T>,
+    /// Returned by `create_user`, required for income verification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_token: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update: Option<LinkUpdate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<LinkTransactions>,
+    /// Generates a Plaid hosted URL for the Link session, returned as
+    /// `hosted_link_url`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_link: Option<HostedLink<T>>,
 }
 
 impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateLinkTokenRequest<'_, T> {
@@ -49,25 +63,342 @@ impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateLinkTokenRequest<'_, T
     }
 }
 
+impl<'a, T: AsRef<str>> CreateLinkTokenRequest<'a, T> {
+    /// Starts a link token request from the fields required in every mode.
+    ///
+    /// ```
+    /// use rplaid::model::*;
+    ///
+    /// let req = CreateLinkTokenRequest::builder("My App", "en", &["US"], LinkUser::new("user-id"))
+    ///     .with_products(&["transactions"])
+    ///     .with_days_requested(365)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder(
+        client_name: T,
+        language: T,
+        country_codes: &'a [T],
+        user: LinkUser<T>,
+    ) -> CreateLinkTokenRequestBuilder<'a, T> {
+        CreateLinkTokenRequestBuilder {
+            req: CreateLinkTokenRequest {
+                client_name,
+                language,
+                country_codes,
+                user,
+                products: &[],
+                webhook: None,
+                access_token: None,
+                link_customization_name: None,
+                redirect_uri: None,
+                android_package_name: None,
+                account_filters: None,
+                eu_config: None,
+                payment_initiation: None,
+                deposit_switch: None,
+                income_verification: None,
+                auth: None,
+                institution_id: None,
+                user_token: None,
+                update: None,
+                transactions: None,
+                hosted_link: None,
+            },
+        }
+    }
+}
+
+/// Error returned when a link token request is missing fields or combines
+/// options Plaid would reject.
+#[derive(thiserror::Error, Debug, Eq, PartialEq, Clone)]
+pub enum LinkTokenError {
+    #[error("{0} must not be empty")]
+    MissingField(&'static str),
+    #[error("products are required unless Link is launched in update mode")]
+    MissingProducts,
+    #[error("account selection can only be enabled in update mode")]
+    AccountSelectionRequiresUpdateMode,
+    #[error("{0} options require the {0} product")]
+    MissingProduct(&'static str),
+    #[error("income verification requires a user token")]
+    MissingUserToken,
+    #[error("transactions days requested must be between 1 and 730, got {0}")]
+    DaysRequestedOutOfRange(u32),
+}
+
+/// Fluent builder for `CreateLinkTokenRequest` that validates the request
+/// before it is sent.
+#[derive(Debug)]
+pub struct CreateLinkTokenRequestBuilder<'a, T: AsRef<str>> {
+    req: CreateLinkTokenRequest<'a, T>,
+}
+
+impl<'a, T: AsRef<str>> CreateLinkTokenRequestBuilder<'a, T> {
+    pub fn with_products(mut self, products: &'a [T]) -> Self {
+        self.req.products = products;
+        self
+    }
+
+    pub fn with_webhook(mut self, webhook: T) -> Self {
+        self.req.webhook = Some(webhook);
+        self
+    }
+
+    /// Launches Link in update mode for the Item the access token belongs to.
+    pub fn with_access_token(mut self, access_token: T) -> Self {
+        self.req.access_token = Some(access_token);
+        self
+    }
+
+    /// Lets the user add or remove accounts for the Item, enabling it
+    /// requires update mode.
+    pub fn with_account_selection_enabled(mut self, enabled: bool) -> Self {
+        self.req.update = Some(LinkUpdate {
+            account_selection_enabled: Some(enabled),
+        });
+        self
+    }
+
+    /// Number of days of transaction history to request, between 1 and 730.
+    pub fn with_days_requested(mut self, days: u32) -> Self {
+        self.req.transactions = Some(LinkTransactions {
+            days_requested: Some(days),
+        });
+        self
+    }
+
+    pub fn with_user_token(mut self, user_token: T) -> Self {
+        self.req.user_token = Some(user_token);
+        self
+    }
+
+    pub fn with_hosted_link(mut self, hosted_link: HostedLink<T>) -> Self {
+        self.req.hosted_link = Some(hosted_link);
+        self
+    }
+
+    pub fn with_link_customization_name(mut self, name: T) -> Self {
+        self.req.link_customization_name = Some(name);
+        self
+    }
+
+    pub fn with_redirect_uri(mut self, redirect_uri: T) -> Self {
+        self.req.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    pub fn with_android_package_name(mut self, package_name: T) -> Self {
+        self.req.android_package_name = Some(package_name);
+        self
+    }
+
+    pub fn with_account_filters(mut self, filters: AccountFilters<'a, T>) -> Self {
+        self.req.account_filters = Some(filters);
+        self
+    }
+
+    pub fn with_eu_config(mut self, config: EUConfig) -> Self {
+        self.req.eu_config = Some(config);
+        self
+    }
+
+    pub fn with_payment_initiation(mut self, config: PaymentInitiation<T>) -> Self {
+        self.req.payment_initiation = Some(config);
+        self
+    }
+
+    pub fn with_deposit_switch(mut self, config: DepositSwitchOptions<T>) -> Self {
+        self.req.deposit_switch = Some(config);
+        self
+    }
+
+    pub fn with_income_verification(mut self, config: IncomeVerification<T>) -> Self {
+        self.req.income_verification = Some(config);
+        self
+    }
+
+    pub fn with_auth(mut self, config: LinkAuth<T>) -> Self {
+        self.req.auth = Some(config);
+        self
+    }
+
+    pub fn with_institution_id(mut self, institution_id: T) -> Self {
+        self.req.institution_id = Some(institution_id);
+        self
+    }
+
+    /// Validates and returns the request.
+    pub fn build(self) -> Result<CreateLinkTokenRequest<'a, T>, LinkTokenError> {
+        let req = self.req;
+
+        if req.client_name.as_ref().is_empty() {
+            return Err(LinkTokenError::MissingField("client_name"));
+        }
+        if req.language.as_ref().is_empty() {
+            return Err(LinkTokenError::MissingField("language"));
+        }
+        if req.country_codes.is_empty() {
+            return Err(LinkTokenError::MissingField("country_codes"));
+        }
+        if req.user.client_user_id.as_ref().is_empty() {
+            return Err(LinkTokenError::MissingField("user.client_user_id"));
+        }
+
+        let update_mode = req.access_token.is_some();
+        if req.products.is_empty() && !update_mode {
+            return Err(LinkTokenError::MissingProducts);
+        }
+        let account_selection = req
+            .update
+            .is_some_and(|update| update.account_selection_enabled == Some(true));
+        if account_selection && !update_mode {
+            return Err(LinkTokenError::AccountSelectionRequiresUpdateMode);
+        }
+
+        // Product configs only apply when creating a new Item, in update mode
+        // the Item's existing products are used.
+        let requires = |product: &'static str| {
+            if update_mode || req.products.iter().any(|p| p.as_ref() == product) {
+                Ok(())
+            } else {
+                Err(LinkTokenError::MissingProduct(product))
+            }
+        };
+        if let Some(transactions) = &req.transactions {
+            requires("transactions")?;
+            if let Some(days) = transactions.days_requested {
+                if !(1..=730).contains(&days) {
+                    return Err(LinkTokenError::DaysRequestedOutOfRange(days));
+                }
+            }
+        }
+        if req.auth.is_some() {
+            requires("auth")?;
+        }
+        if req.payment_initiation.is_some() {
+            requires("payment_initiation")?;
+        }
+        if req.deposit_switch.is_some() {
+            requires("deposit_switch")?;
+        }
+        if req.income_verification.is_some() {
+            requires("income_verification")?;
+            if req.user_token.is_none() {
+                return Err(LinkTokenError::MissingUserToken);
+            }
+        }
+
+        Ok(req)
+    }
+}
+
+#[derive(Debug, Serialize, Default, Clone, Copy)]
+pub struct LinkUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_selection_enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Default, Clone, Copy)]
+pub struct LinkTransactions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_requested: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct HostedLink<T: AsRef<str>> {
+    /// One of email or sms, when set Plaid sends the URL to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_method: Option<T>,
+    /// URI the user is sent to after completing the Link flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_redirect_uri: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_seconds: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mobile_app: Option<bool>,
+}
+
 #[derive(Debug, Serialize, Default)]
 pub struct LinkAuth<T: AsRef<str>> {
-    flow_type: T,
+    /// DEPRECATED: use the `*_enabled` flags instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow_type: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_type_select_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automated_microdeposits_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instant_match_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub same_day_microdeposits_enabled: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Default)]
 pub struct IncomeVerification<T: AsRef<str>> {
-    income_verification_id: T,
-    asset_report_id: Option<T>,
+    /// DEPRECATED: income verifications are now tied to `user_token`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub income_verification_id: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_report_id: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_income: Option<LinkBankIncome>,
+}
+
+#[derive(Debug, Serialize, Default, Clone, Copy)]
+pub struct LinkBankIncome {
+    /// Number of days of history to use for bank income, up to 731.
+    pub days_requested: u32,
+}
+
+impl<T: AsRef<str>> IncomeVerification<T> {
+    /// Configures Link for bank income with the given history length.
+    pub fn bank_income(days_requested: u32) -> Self {
+        Self {
+            income_verification_id: None,
+            asset_report_id: None,
+            bank_income: Some(LinkBankIncome { days_requested }),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Default)]
 pub struct DepositSwitchOptions<T: AsRef<str>> {
-    deposit_switch_id: T,
+    pub deposit_switch_id: T,
+}
+
+impl<T: AsRef<str>> DepositSwitchOptions<T> {
+    /// Configures Link for a deposit switch created by `create_deposit_switch`.
+    pub fn new(deposit_switch_id: T) -> Self {
+        Self { deposit_switch_id }
+    }
 }
 
 #[derive(Debug, Serialize, Default)]
 pub struct PaymentInitiation<T: AsRef<str>> {
-    payment_id: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_id: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent_id: Option<T>,
+}
+
+impl<T: AsRef<str>> PaymentInitiation<T> {
+    /// Configures Link to authorise a payment created by `create_payment`.
+    pub fn payment(payment_id: T) -> Self {
+        Self {
+            payment_id: Some(payment_id),
+            consent_id: None,
+        }
+    }
+
+    /// Configures Link to authorise a consent created by
+    /// `create_payment_consent`.
+    pub fn consent(consent_id: T) -> Self {
+        Self {
+            payment_id: None,
+            consent_id: Some(consent_id),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Default)]
@@ -84,20 +415,39 @@ pub struct LinkUser<T: AsRef<str>> {
 
 #[derive(Debug, Serialize, Default)]
 pub struct AccountFilters<'a, T: AsRef<str>> {
-    depository: Option<AccountFilter<'a, T>>,
-    credit: Option<AccountFilter<'a, T>>,
-    loan: Option<AccountFilter<'a, T>>,
-    investment: Option<AccountFilter<'a, T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depository: Option<AccountFilter<'a, T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit: Option<AccountFilter<'a, T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan: Option<AccountFilter<'a, T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investment: Option<AccountFilter<'a, T>>,
 }
 
 #[derive(Debug, Serialize, Default)]
 pub struct EUConfig {
-    headless: Option<bool>,
+    pub headless: Option<bool>,
+}
+
+impl EUConfig {
+    pub fn new(headless: bool) -> Self {
+        Self {
+            headless: Some(headless),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Default)]
 pub struct AccountFilter<'a, T: AsRef<str>> {
-    account_subtypes: &'a [T],
+    /// Account subtypes to show in Link, e.g. checking or savings.
+    pub account_subtypes: &'a [T],
+}
+
+impl<'a, T: AsRef<str>> AccountFilter<'a, T> {
+    pub fn new(account_subtypes: &'a [T]) -> Self {
+        Self { account_subtypes }
+    }
 }
 
 impl<T: AsRef<str> + Default> LinkUser<T> {
@@ -113,6 +463,8 @@ impl<T: AsRef<str> + Default> LinkUser<T> {
 pub struct CreateLinkTokenResponse {
     pub link_token: String,
     pub expiration: String,
+    /// Only returned when `hosted_link` is set.
+    pub hosted_link_url: Option<String>,
     pub request_id: String,
 }
 
@@ -134,9 +486,150 @@ pub struct GetLinkTokenResponse {
     pub link_token: String,
     pub expiration: Option<String>,
     pub created_at: Option<String>,
+    /// The configuration the link token was created with.
+    pub metadata: Option<LinkTokenMetadata>,
+    /// Link sessions started with the token, only returned for Hosted Link
+    /// and Link delivery sessions.
+    #[serde(default)]
+    pub link_sessions: Vec<LinkSession>,
     pub request_id: String,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkTokenMetadata {
+    #[serde(default)]
+    pub initial_products: Vec<String>,
+    pub webhook: Option<String>,
+    #[serde(default)]
+    pub country_codes: Vec<String>,
+    pub language: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub client_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkSession {
+    pub link_session_id: String,
+    /// Timestamp the session started, in ISO 8601 format.
+    pub started_at: Option<String>,
+    /// Timestamp the session finished, none while the session is in progress.
+    pub finished_at: Option<String>,
+    /// Set when the session completed successfully, equivalent to Link's
+    /// `onSuccess` callback.
+    pub on_success: Option<LinkSessionSuccess>,
+    /// Set when the user exited the session, equivalent to Link's `onExit`
+    /// callback.
+    pub on_exit: Option<LinkSessionExit>,
+    pub results: Option<LinkSessionResults>,
+    #[serde(default)]
+    pub events: Vec<LinkEvent>,
+}
+
+impl LinkSession {
+    /// Returns public tokens created during the session, from both the
+    /// `on_success` result and multi-item results.
+    pub fn public_tokens(&self) -> Vec<&str> {
+        let mut tokens: Vec<&str> = self
+            .on_success
+            .iter()
+            .map(|success| success.public_token.as_str())
+            .collect();
+        for result in self.results.iter().flat_map(|r| r.item_add_results.iter()) {
+            if !tokens.contains(&result.public_token.as_str()) {
+                tokens.push(&result.public_token);
+            }
+        }
+        tokens
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkSessionSuccess {
+    pub public_token: String,
+    pub metadata: Option<LinkSessionSuccessMetadata>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkSessionSuccessMetadata {
+    pub institution: Option<LinkInstitution>,
+    #[serde(default)]
+    pub accounts: Vec<LinkAccount>,
+    pub link_session_id: Option<String>,
+    pub transfer_status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkSessionExit {
+    /// Set when the user exited because of an error.
+    pub error: Option<ErrorResponse>,
+    pub metadata: Option<LinkSessionExitMetadata>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkSessionExitMetadata {
+    pub institution: Option<LinkInstitution>,
+    /// The point at which the user exited, e.g. requires_credentials or
+    /// institution_not_found.
+    pub status: Option<String>,
+    pub link_session_id: Option<String>,
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkSessionResults {
+    /// One result per Item added during the session.
+    #[serde(default)]
+    pub item_add_results: Vec<LinkItemAddResult>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkItemAddResult {
+    pub public_token: String,
+    #[serde(default)]
+    pub accounts: Vec<LinkAccount>,
+    pub institution: Option<LinkInstitution>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LinkInstitution {
+    pub name: String,
+    pub institution_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LinkAccount {
+    pub id: String,
+    pub name: String,
+    pub mask: Option<String>,
+    pub r#type: AccountType,
+    pub subtype: Option<String>,
+    /// Only set for accounts verified with micro-deposits or database
+    /// matching, e.g. pending_manual_verification.
+    pub verification_status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkEvent {
+    /// The Link event, e.g. OPEN, SELECT_INSTITUTION or HANDOFF.
+    pub event_name: String,
+    /// Timestamp of the event, in ISO 8601 format.
+    pub timestamp: String,
+    pub event_id: String,
+    pub event_metadata: Option<LinkEventMetadata>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LinkEventMetadata {
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub exit_status: Option<String>,
+    pub institution_id: Option<String>,
+    pub institution_name: Option<String>,
+    pub institution_search_query: Option<String>,
+    pub request_id: Option<String>,
+    pub view_name: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct InvalidateAccessTokenRequest<T: AsRef<str>> {
     pub access_token: T,
@@ -155,3 +648,146 @@ pub struct InvalidateAccessTokenResponse {
     pub new_access_token: String,
     pub request_id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> CreateLinkTokenRequestBuilder<'static, &'static str> {
+        CreateLinkTokenRequest::builder("test_client", "en", &["US"], LinkUser::new("user"))
+    }
+
+    fn error(builder: CreateLinkTokenRequestBuilder<'_, &str>) -> LinkTokenError {
+        builder.build().unwrap_err()
+    }
+
+    #[test]
+    fn requires_fields() {
+        let user = || LinkUser::new("user");
+        let cases = [
+            (
+                CreateLinkTokenRequest::builder("", "en", &["US"], user()),
+                "client_name",
+            ),
+            (
+                CreateLinkTokenRequest::builder("test_client", "", &["US"], user()),
+                "language",
+            ),
+            (
+                CreateLinkTokenRequest::builder("test_client", "en", &[], user()),
+                "country_codes",
+            ),
+            (
+                CreateLinkTokenRequest::builder("test_client", "en", &["US"], LinkUser::new("")),
+                "user.client_user_id",
+            ),
+        ];
+
+        for (builder, field) in cases {
+            assert_eq!(
+                error(builder.with_products(&["auth"])),
+                LinkTokenError::MissingField(field)
+            );
+        }
+    }
+
+    #[test]
+    fn requires_products_outside_update_mode() {
+        assert_eq!(error(builder()), LinkTokenError::MissingProducts);
+        assert!(builder()
+            .with_access_token("access-sandbox-token")
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn product_options_require_their_product() {
+        assert_eq!(
+            error(builder().with_products(&["auth"]).with_days_requested(90)),
+            LinkTokenError::MissingProduct("transactions")
+        );
+        assert_eq!(
+            error(
+                builder()
+                    .with_products(&["transactions"])
+                    .with_auth(LinkAuth::default())
+            ),
+            LinkTokenError::MissingProduct("auth")
+        );
+        assert_eq!(
+            error(
+                builder()
+                    .with_products(&["auth"])
+                    .with_income_verification(IncomeVerification::default())
+            ),
+            LinkTokenError::MissingProduct("income_verification")
+        );
+        // Options apply to the Item's existing products in update mode.
+        assert!(builder()
+            .with_access_token("access-sandbox-token")
+            .with_days_requested(90)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn income_verification_requires_user_token() {
+        let builder = || {
+            builder()
+                .with_products(&["income_verification"])
+                .with_income_verification(IncomeVerification::default())
+        };
+
+        assert_eq!(error(builder()), LinkTokenError::MissingUserToken);
+        assert!(builder()
+            .with_user_token("user-sandbox-token")
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn days_requested_must_be_in_range() {
+        let builder = || builder().with_products(&["transactions"]);
+
+        assert_eq!(
+            error(builder().with_days_requested(0)),
+            LinkTokenError::DaysRequestedOutOfRange(0)
+        );
+        assert_eq!(
+            error(builder().with_days_requested(731)),
+            LinkTokenError::DaysRequestedOutOfRange(731)
+        );
+        assert!(builder().with_days_requested(730).build().is_ok());
+    }
+
+    #[test]
+    fn account_selection_requires_update_mode() {
+        let builder = || builder().with_products(&["transactions"]);
+
+        assert_eq!(
+            error(builder().with_account_selection_enabled(true)),
+            LinkTokenError::AccountSelectionRequiresUpdateMode
+        );
+        assert!(builder()
+            .with_account_selection_enabled(false)
+            .build()
+            .is_ok());
+        assert!(builder()
+            .with_access_token("access-sandbox-token")
+            .with_account_selection_enabled(true)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn update_mode_omits_products() {
+        let req = builder()
+            .with_access_token("access-sandbox-token")
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&req).unwrap();
+
+        assert!(json.get("products").is_none());
+        assert_eq!(json["access_token"], "access-sandbox-token");
+    }
+}
@@ -106,6 +106,91 @@ pub struct RefreshTransactionsResponse {
     pub request_id: String,
 }
 
+#[derive(Debug, Serialize, Copy, Clone)]
+pub struct GetRecurringTransactionsRequest<'a, T: AsRef<str>> {
+    pub access_token: T,
+    /// Accounts to return recurring streams for, defaults to all accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_ids: Option<&'a [T]>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetRecurringTransactionsRequest<'_, T> {
+    type Response = GetRecurringTransactionsResponse;
+
+    fn path(&self) -> String {
+        "/transactions/recurring/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GetRecurringTransactionsResponse {
+    /// Streams of money flowing into the accounts, e.g. payroll deposits.
+    pub inflow_streams: Vec<RecurringTransactionStream>,
+    /// Streams of money flowing out of the accounts, e.g. subscriptions.
+    pub outflow_streams: Vec<RecurringTransactionStream>,
+    /// Timestamp of the last time streams were updated, in ISO 8601 format.
+    pub updated_datetime: String,
+    pub request_id: String,
+}
+
+/// A group of transactions identified as recurring, such as a subscription
+/// or a paycheck.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RecurringTransactionStream {
+    pub account_id: String,
+    pub stream_id: String,
+    pub category_id: Option<String>,
+    pub category: Option<Vec<String>>,
+    /// A description of the transaction stream.
+    pub description: String,
+    pub merchant_name: Option<String>,
+    /// The posted date of the earliest transaction in the stream.
+    pub first_date: String,
+    /// The posted date of the latest transaction in the stream.
+    pub last_date: String,
+    pub frequency: RecurringTransactionFrequency,
+    pub transaction_ids: Vec<String>,
+    pub average_amount: RecurringTransactionAmount,
+    pub last_amount: RecurringTransactionAmount,
+    /// Whether the stream is still expected to recur.
+    pub is_active: bool,
+    pub status: RecurringTransactionStreamStatus,
+    pub personal_finance_category: Option<PersonalFinanceCategory>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecurringTransactionFrequency {
+    Unknown,
+    Weekly,
+    Biweekly,
+    SemiMonthly,
+    Monthly,
+    Annually,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecurringTransactionStreamStatus {
+    Unknown,
+    /// At least three transactions have occurred at a regular interval.
+    Mature,
+    /// Fewer than three transactions have occurred at a regular interval.
+    EarlyDetection,
+    /// The stream was previously detected but has stopped recurring.
+    Tombstoned,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RecurringTransactionAmount {
+    #[cfg(not(feature = "decimal"))]
+    pub amount: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub amount: Option<Decimal>,
+    pub iso_currency_code: Option<String>,
+    pub unofficial_currency_code: Option<String>,
+}
+
 #[derive(Debug, Serialize, Copy, Clone)]
 pub struct GetCategoriesRequest {}
 
@@ -174,6 +259,22 @@ pub struct Transaction {
     pub check_number: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_code: Option<String>,
+    /// Only returned when `include_personal_finance_category` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_finance_category: Option<PersonalFinanceCategory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_finance_category_icon_url: Option<String>,
+    /// The URL of a logo for the merchant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The merchant's website.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    /// A unique ID identifying the merchant, stable across transactions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_entity_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub counterparties: Vec<Counterparty>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
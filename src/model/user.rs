@@ -0,0 +1,112 @@
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct CreateUserRequest<'a, T: AsRef<str>> {
+    /// A unique ID representing the end user, maximum of 128 characters.
+    /// Should not contain personally identifiable information.
+    pub client_user_id: T,
+    /// Required to generate Consumer Reports for the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_report_user_identity: Option<ConsumerReportUserIdentity<'a, T>>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateUserRequest<'_, T> {
+    type Response = CreateUserResponse;
+
+    fn path(&self) -> String {
+        "/user/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateUserResponse {
+    /// Token used to identify the user in calls to products such as income
+    /// verification.
+    pub user_token: String,
+    pub user_id: String,
+    pub request_id: String,
+}
+
+/// Identity of the end user, used by Consumer Report products.
+#[derive(Debug, Serialize)]
+pub struct ConsumerReportUserIdentity<'a, T: AsRef<str>> {
+    pub first_name: T,
+    pub last_name: T,
+    /// Phone numbers in E.164 format, e.g. "+14155550015".
+    pub phone_numbers: &'a [T],
+    pub emails: &'a [T],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<T>,
+    /// Date of birth in ISO 8601 format (YYYY-MM-DD).
+    pub date_of_birth: T,
+    pub primary_address: ConsumerReportUserAddress<T>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConsumerReportUserAddress<T: AsRef<str>> {
+    pub city: T,
+    /// The region or state, e.g. "NC".
+    pub region: T,
+    pub street: T,
+    pub postal_code: T,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: T,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateUserRequest<'a, T: AsRef<str>> {
+    pub user_token: T,
+    pub consumer_report_user_identity: ConsumerReportUserIdentity<'a, T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for UpdateUserRequest<'_, T> {
+    type Response = UpdateUserResponse;
+
+    fn path(&self) -> String {
+        "/user/update".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateUserResponse {
+    pub request_id: String,
+}
+
+/// Removes a user and every Item associated with it, invalidating the
+/// `user_token` and the Items' access tokens.
+#[derive(Debug, Serialize)]
+pub struct RemoveUserRequest<T: AsRef<str>> {
+    pub user_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for RemoveUserRequest<T> {
+    type Response = RemoveUserResponse;
+
+    fn path(&self) -> String {
+        "/user/remove".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RemoveUserResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetUserItemsRequest<T: AsRef<str>> {
+    pub user_token: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetUserItemsRequest<T> {
+    type Response = GetUserItemsResponse;
+
+    fn path(&self) -> String {
+        "/user/items/get".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetUserItemsResponse {
+    pub items: Vec<Item>,
+    pub request_id: String,
+}
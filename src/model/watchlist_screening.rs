@@ -0,0 +1,213 @@
+use super::*;
+
+#[derive(Debug, Serialize)]
+pub struct CreateWatchlistScreeningRequest<T: AsRef<str>> {
+    pub search_terms: WatchlistScreeningSearchTerms<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_user_id: Option<T>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct WatchlistScreeningSearchTerms<T: AsRef<str>> {
+    /// ID of the watchlist program configured in the Plaid dashboard.
+    pub watchlist_program_id: T,
+    pub legal_name: T,
+    /// Date of birth in the format YYYY-MM-DD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_number: Option<T>,
+    /// ISO 3166-1 alpha-2 country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateWatchlistScreeningRequest<T> {
+    type Response = WatchlistScreeningResponse;
+
+    fn path(&self) -> String {
+        "/watchlist_screening/individual/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WatchlistScreeningResponse {
+    #[serde(flatten)]
+    pub watchlist_screening: WatchlistScreening,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WatchlistScreening {
+    pub id: String,
+    pub search_terms: WatchlistScreeningSearchTermsResponse,
+    /// ID of the dashboard user assigned to review the screening.
+    pub assignee: Option<String>,
+    pub status: WatchlistScreeningStatus,
+    pub client_user_id: Option<String>,
+    pub audit_trail: WatchlistScreeningAuditTrail,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WatchlistScreeningSearchTermsResponse {
+    pub watchlist_program_id: String,
+    pub legal_name: String,
+    pub date_of_birth: Option<String>,
+    pub document_number: Option<String>,
+    pub country: Option<String>,
+    /// Incremented each time the search terms are updated.
+    pub version: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchlistScreeningStatus {
+    Rejected,
+    PendingReview,
+    Cleared,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WatchlistScreeningAuditTrail {
+    /// One of dashboard or link.
+    pub source: String,
+    pub dashboard_user_id: Option<String>,
+    /// Timestamp of the change, in ISO 8601 format.
+    pub timestamp: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetWatchlistScreeningRequest<T: AsRef<str>> {
+    pub watchlist_screening_id: T,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for GetWatchlistScreeningRequest<T> {
+    type Response = WatchlistScreeningResponse;
+
+    fn path(&self) -> String {
+        "/watchlist_screening/individual/get".into()
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ListWatchlistScreeningsRequest<T: AsRef<str>> {
+    pub watchlist_program_id: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_user_id: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<WatchlistScreeningStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for ListWatchlistScreeningsRequest<T> {
+    type Response = ListWatchlistScreeningsResponse;
+
+    fn path(&self) -> String {
+        "/watchlist_screening/individual/list".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListWatchlistScreeningsResponse {
+    pub watchlist_screenings: Vec<WatchlistScreening>,
+    /// Cursor for the next page of results, none when there are no more
+    /// results.
+    pub next_cursor: Option<String>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateWatchlistScreeningReviewRequest<'a, T: AsRef<str>> {
+    pub watchlist_screening_id: T,
+    /// Hits to mark as a true positive match.
+    pub confirmed_hits: &'a [T],
+    /// Hits to mark as a false positive.
+    pub dismissed_hits: &'a [T],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<T>,
+}
+
+impl<T: AsRef<str> + serde::Serialize> Endpoint for CreateWatchlistScreeningReviewRequest<'_, T> {
+    type Response = WatchlistScreeningReviewResponse;
+
+    fn path(&self) -> String {
+        "/watchlist_screening/individual/review/create".into()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WatchlistScreeningReviewResponse {
+    pub id: String,
+    pub confirmed_hits: Vec<String>,
+    pub dismissed_hits: Vec<String>,
+    /// The reviewer's comment, if they left one.
+    pub comment: Option<String>,
+    pub audit_trail: WatchlistScreeningAuditTrail,
+    pub request_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_watchlist_screening() {
+        let res: WatchlistScreeningResponse = serde_json::from_str(
+            r#"{
+                "id": "scr_52xR9LKo77r1Np",
+                "search_terms": {
+                    "watchlist_program_id": "prg_2eRPsDnL66rZ7H",
+                    "legal_name": "Aleksey Potemkin",
+                    "date_of_birth": "1990-05-29",
+                    "document_number": null,
+                    "country": "US",
+                    "version": 1
+                },
+                "assignee": null,
+                "status": "pending_review",
+                "client_user_id": "your-db-id-3b24110",
+                "audit_trail": {
+                    "source": "dashboard",
+                    "dashboard_user_id": "usr_1SUuwqBdK75GKi",
+                    "timestamp": "2020-07-24T03:26:02Z"
+                },
+                "request_id": "saKrIBuEB9qJZng"
+            }"#,
+        )
+        .unwrap();
+
+        let screening = res.watchlist_screening;
+        assert_eq!(screening.status, WatchlistScreeningStatus::PendingReview);
+        assert_eq!(screening.search_terms.legal_name, "Aleksey Potemkin");
+        assert_eq!(screening.search_terms.document_number, None);
+        assert_eq!(res.request_id, "saKrIBuEB9qJZng");
+    }
+
+    #[test]
+    fn deserializes_review_comment() {
+        let res: WatchlistScreeningReviewResponse = serde_json::from_str(
+            r#"{
+                "id": "rev_aCLNRxK3UVzn2r",
+                "confirmed_hits": [],
+                "dismissed_hits": ["scrhit_52xR9LKo77r1Np"],
+                "comment": "These look like legitimate matches, rejecting the customer.",
+                "audit_trail": {
+                    "source": "dashboard",
+                    "dashboard_user_id": "usr_1SUuwqBdK75GKi",
+                    "timestamp": "2020-07-24T03:26:02Z"
+                },
+                "request_id": "saKrIBuEB9qJZng"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.comment.as_deref(),
+            Some("These look like legitimate matches, rejecting the customer.")
+        );
+        assert_eq!(res.dismissed_hits, ["scrhit_52xR9LKo77r1Np"]);
+    }
+}
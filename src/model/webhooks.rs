@@ -15,9 +15,486 @@ impl<T: AsRef<str> + serde::Serialize> Endpoint for GetWebhookVerificationKeyReq
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetWebhookVerificationKeyResponse {
-    // TODO(allancalix): This is obviously not right, but maybe it's worth
-    // bringing in a real JWT type to return here? Creating a JWT type to
-    // return here doesn't feel like the right answer.
-    pub key: std::collections::HashMap<String, String>,
+    pub key: Jwk,
     pub request_id: String,
 }
+
+/// A JSON Web Key used to verify the signature of webhooks sent by Plaid.
+/// Plaid signs webhooks with ES256, keys are P-256 elliptic curve public keys.
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+pub struct Jwk {
+    /// Signing algorithm, always ES256.
+    pub alg: String,
+    /// Curve name, always P-256.
+    pub crv: String,
+    /// Key ID, matches the `kid` header of the webhook JWT.
+    pub kid: String,
+    /// Key type, always EC.
+    pub kty: String,
+    #[serde(rename = "use")]
+    pub key_use: String,
+    /// Base64url encoded x coordinate of the public key.
+    pub x: String,
+    /// Base64url encoded y coordinate of the public key.
+    pub y: String,
+    /// Unix timestamp the key was created at.
+    pub created_at: u64,
+    /// Unix timestamp the key expired at, none while the key is valid.
+    pub expired_at: Option<u64>,
+}
+
+macro_rules! webhooks {
+    ($(
+        $product:ident($product_webhook:ident) => $webhook_type:literal {
+            $($variant:ident($payload:ty) => $webhook_code:literal,)+
+        }
+    )+) => {
+        /// A webhook sent by Plaid, identified by its `webhook_type` and
+        /// `webhook_code`. Webhooks this crate doesn't model are parsed as
+        /// `Unknown`, and known webhooks whose payload doesn't match the model
+        /// as `Malformed`, rather than failing.
+        ///
+        /// https://plaid.com/docs/api/webhooks/
+        #[derive(Debug, Clone)]
+        pub enum Webhook {
+            $($product($product_webhook),)+
+            Unknown(UnknownWebhook),
+            Malformed(MalformedWebhook),
+        }
+
+        $(
+            #[derive(Debug, Clone)]
+            pub enum $product_webhook {
+                $($variant($payload),)+
+            }
+        )+
+
+        impl Webhook {
+            pub fn webhook_type(&self) -> &str {
+                match self {
+                    $(Self::$product(_) => $webhook_type,)+
+                    Self::Unknown(webhook) => &webhook.webhook_type,
+                    Self::Malformed(webhook) => &webhook.webhook_type,
+                }
+            }
+
+            pub fn webhook_code(&self) -> &str {
+                match self {
+                    $($(Self::$product($product_webhook::$variant(_)) => $webhook_code,)+)+
+                    Self::Unknown(webhook) => &webhook.webhook_code,
+                    Self::Malformed(webhook) => &webhook.webhook_code,
+                }
+            }
+
+            fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+                let tag = WebhookTag::deserialize(&value)?;
+
+                Ok(match (tag.webhook_type.as_str(), tag.webhook_code.as_str()) {
+                    $($(($webhook_type, $webhook_code) => {
+                        match <$payload>::deserialize(&value) {
+                            Ok(payload) => Self::$product($product_webhook::$variant(payload)),
+                            Err(err) => Self::Malformed(MalformedWebhook {
+                                webhook_type: tag.webhook_type,
+                                webhook_code: tag.webhook_code,
+                                error: err.to_string(),
+                                body: value,
+                            }),
+                        }
+                    })+)+
+                    _ => Self::Unknown(UnknownWebhook {
+                        webhook_type: tag.webhook_type,
+                        webhook_code: tag.webhook_code,
+                        body: value,
+                    }),
+                })
+            }
+
+            fn to_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+                let mut value = match self {
+                    $($(Self::$product($product_webhook::$variant(payload)) => {
+                        serde_json::to_value(payload)?
+                    })+)+
+                    Self::Unknown(webhook) => webhook.body.clone(),
+                    Self::Malformed(webhook) => webhook.body.clone(),
+                };
+                if let serde_json::Value::Object(fields) = &mut value {
+                    fields.insert("webhook_type".into(), self.webhook_type().into());
+                    fields.insert("webhook_code".into(), self.webhook_code().into());
+                }
+
+                Ok(value)
+            }
+        }
+    };
+}
+
+webhooks! {
+    Transactions(TransactionsWebhook) => "TRANSACTIONS" {
+        SyncUpdatesAvailable(SyncUpdatesAvailableWebhook) => "SYNC_UPDATES_AVAILABLE",
+        InitialUpdate(TransactionsUpdateWebhook) => "INITIAL_UPDATE",
+        HistoricalUpdate(TransactionsUpdateWebhook) => "HISTORICAL_UPDATE",
+        DefaultUpdate(TransactionsUpdateWebhook) => "DEFAULT_UPDATE",
+        TransactionsRemoved(TransactionsRemovedWebhook) => "TRANSACTIONS_REMOVED",
+        RecurringTransactionsUpdate(RecurringTransactionsUpdateWebhook) => "RECURRING_TRANSACTIONS_UPDATE",
+    }
+    Item(ItemWebhook) => "ITEM" {
+        Error(ItemErrorWebhook) => "ERROR",
+        PendingExpiration(PendingExpirationWebhook) => "PENDING_EXPIRATION",
+        UserPermissionRevoked(ItemErrorWebhook) => "USER_PERMISSION_REVOKED",
+        UserAccountRevoked(UserAccountRevokedWebhook) => "USER_ACCOUNT_REVOKED",
+        WebhookUpdateAcknowledged(WebhookUpdateAcknowledgedWebhook) => "WEBHOOK_UPDATE_ACKNOWLEDGED",
+        NewAccountsAvailable(ItemErrorWebhook) => "NEW_ACCOUNTS_AVAILABLE",
+        LoginRepaired(ItemErrorWebhook) => "LOGIN_REPAIRED",
+    }
+    Auth(AuthWebhook) => "AUTH" {
+        AutomaticallyVerified(AuthVerificationWebhook) => "AUTOMATICALLY_VERIFIED",
+        VerificationExpired(AuthVerificationWebhook) => "VERIFICATION_EXPIRED",
+        DefaultUpdate(AuthDefaultUpdateWebhook) => "DEFAULT_UPDATE",
+    }
+    Assets(AssetsWebhook) => "ASSETS" {
+        ProductReady(AssetsWebhookPayload) => "PRODUCT_READY",
+        Error(AssetsWebhookPayload) => "ERROR",
+    }
+    Transfer(TransferWebhook) => "TRANSFER" {
+        TransferEventsUpdate(TransferEventsUpdateWebhook) => "TRANSFER_EVENTS_UPDATE",
+    }
+    Income(IncomeWebhook) => "INCOME" {
+        IncomeVerification(IncomeVerificationWebhook) => "INCOME_VERIFICATION",
+        IncomeVerificationRiskSignals(IncomeVerificationWebhook) => "INCOME_VERIFICATION_RISK_SIGNALS",
+        BankIncomeRefreshComplete(BankIncomeRefreshCompleteWebhook) => "BANK_INCOME_REFRESH_COMPLETE",
+    }
+    Statements(StatementsWebhook) => "STATEMENTS" {
+        StatementsRefreshComplete(StatementsRefreshCompleteWebhook) => "STATEMENTS_REFRESH_COMPLETE",
+    }
+    PaymentInitiation(PaymentInitiationWebhook) => "PAYMENT_INITIATION" {
+        PaymentStatusUpdate(PaymentStatusUpdateWebhook) => "PAYMENT_STATUS_UPDATE",
+    }
+    DepositSwitch(DepositSwitchWebhook) => "DEPOSIT_SWITCH" {
+        SwitchStateUpdate(SwitchStateUpdateWebhook) => "SWITCH_STATE_UPDATE",
+    }
+    IdentityVerification(IdentityVerificationWebhook) => "IDENTITY_VERIFICATION" {
+        StatusUpdated(IdentityVerificationWebhookPayload) => "STATUS_UPDATED",
+        StepUpdated(IdentityVerificationWebhookPayload) => "STEP_UPDATED",
+        Retried(IdentityVerificationWebhookPayload) => "RETRIED",
+    }
+    Screening(ScreeningWebhook) => "SCREENING" {
+        StatusUpdated(ScreeningStatusUpdatedWebhook) => "STATUS_UPDATED",
+    }
+}
+
+impl Webhook {
+    /// Parses a webhook from the raw request body Plaid POSTs to the webhook
+    /// URL.
+    pub fn from_slice(body: &[u8]) -> Result<Self, serde_json::Error> {
+        Self::from_value(serde_json::from_slice(body)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for Webhook {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Self::from_value(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for Webhook {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[derive(Deserialize)]
+struct WebhookTag {
+    webhook_type: String,
+    webhook_code: String,
+}
+
+/// A webhook with a type or code this crate doesn't model.
+#[derive(Debug, Clone)]
+pub struct UnknownWebhook {
+    pub webhook_type: String,
+    pub webhook_code: String,
+    /// The full webhook body, including `webhook_type` and `webhook_code`.
+    pub body: serde_json::Value,
+}
+
+/// A webhook with a known type and code whose payload doesn't match this
+/// crate's model, e.g. because Plaid added an enum value or renamed a field.
+#[derive(Debug, Clone)]
+pub struct MalformedWebhook {
+    pub webhook_type: String,
+    pub webhook_code: String,
+    /// Why the payload couldn't be parsed.
+    pub error: String,
+    /// The full webhook body, including `webhook_type` and `webhook_code`.
+    pub body: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SyncUpdatesAvailableWebhook {
+    pub item_id: String,
+    /// True once the first 30 days of transactions are available.
+    pub initial_update_complete: bool,
+    /// True once all historical transactions are available.
+    pub historical_update_complete: bool,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TransactionsUpdateWebhook {
+    pub item_id: String,
+    pub new_transactions: usize,
+    pub error: Option<ErrorResponse>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TransactionsRemovedWebhook {
+    pub item_id: String,
+    pub removed_transactions: Vec<String>,
+    pub error: Option<ErrorResponse>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RecurringTransactionsUpdateWebhook {
+    pub item_id: String,
+    pub account_ids: Vec<String>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ItemErrorWebhook {
+    pub item_id: String,
+    pub error: Option<ErrorResponse>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PendingExpirationWebhook {
+    pub item_id: String,
+    /// Date and time the Item's access consent will expire, in ISO 8601
+    /// format.
+    pub consent_expiration_time: Option<String>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserAccountRevokedWebhook {
+    pub item_id: String,
+    pub account_id: String,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WebhookUpdateAcknowledgedWebhook {
+    pub item_id: String,
+    pub new_webhook_url: String,
+    pub error: Option<ErrorResponse>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AuthVerificationWebhook {
+    pub item_id: String,
+    pub account_id: String,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AuthDefaultUpdateWebhook {
+    pub item_id: String,
+    #[serde(default)]
+    pub account_ids_with_new_auth: Vec<String>,
+    pub error: Option<ErrorResponse>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AssetsWebhookPayload {
+    pub asset_report_id: String,
+    /// One of FULL or FAST, only set for PRODUCT_READY.
+    pub report_type: Option<String>,
+    pub error: Option<ErrorResponse>,
+    pub environment: Option<String>,
+}
+
+/// Signals that new transfer events are available from
+/// `/transfer/event/sync`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TransferEventsUpdateWebhook {
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IncomeVerificationWebhook {
+    pub item_id: Option<String>,
+    pub user_id: Option<String>,
+    /// e.g. VERIFICATION_STATUS_PROCESSING_COMPLETE, or the risk signal
+    /// status for INCOME_VERIFICATION_RISK_SIGNALS.
+    #[serde(alias = "status")]
+    pub verification_status: String,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct BankIncomeRefreshCompleteWebhook {
+    pub user_id: String,
+    /// One of SUCCESS or FAILURE.
+    pub result: String,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct StatementsRefreshCompleteWebhook {
+    pub item_id: String,
+    /// One of SUCCESS or FAILURE.
+    pub result: String,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentStatusUpdateWebhook {
+    pub payment_id: String,
+    pub new_payment_status: PaymentStatus,
+    pub old_payment_status: PaymentStatus,
+    pub original_reference: Option<String>,
+    pub adjusted_reference: Option<String>,
+    pub original_start_date: Option<String>,
+    pub adjusted_start_date: Option<String>,
+    /// Timestamp of the status change, in ISO 8601 format.
+    pub timestamp: String,
+    pub error: Option<ErrorResponse>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SwitchStateUpdateWebhook {
+    pub deposit_switch_id: String,
+    pub state: DepositSwitchState,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IdentityVerificationWebhookPayload {
+    pub identity_verification_id: String,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ScreeningStatusUpdatedWebhook {
+    pub screening_id: String,
+    pub environment: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_webhook() {
+        let webhook = Webhook::from_slice(
+            br#"{
+                "webhook_type": "TRANSACTIONS",
+                "webhook_code": "SYNC_UPDATES_AVAILABLE",
+                "item_id": "wz666MBjYWTp2PDzzggYhM6oWWmBb",
+                "initial_update_complete": true,
+                "historical_update_complete": false,
+                "environment": "production"
+            }"#,
+        )
+        .unwrap();
+
+        match &webhook {
+            Webhook::Transactions(TransactionsWebhook::SyncUpdatesAvailable(payload)) => {
+                assert_eq!(payload.item_id, "wz666MBjYWTp2PDzzggYhM6oWWmBb");
+                assert!(payload.initial_update_complete);
+                assert!(!payload.historical_update_complete);
+            }
+            _ => panic!("unexpected webhook {:?}", webhook),
+        }
+        assert_eq!(webhook.webhook_type(), "TRANSACTIONS");
+        assert_eq!(webhook.webhook_code(), "SYNC_UPDATES_AVAILABLE");
+    }
+
+    #[test]
+    fn same_code_parses_by_type() {
+        let item = Webhook::from_slice(
+            br#"{"webhook_type": "ITEM", "webhook_code": "ERROR", "item_id": "item", "error": null}"#,
+        )
+        .unwrap();
+        let assets = Webhook::from_slice(
+            br#"{"webhook_type": "ASSETS", "webhook_code": "ERROR", "asset_report_id": "report"}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(item, Webhook::Item(ItemWebhook::Error(_))));
+        assert!(matches!(assets, Webhook::Assets(AssetsWebhook::Error(_))));
+    }
+
+    #[test]
+    fn unmodeled_webhooks_are_unknown() {
+        let body =
+            br#"{"webhook_type": "HOLDINGS", "webhook_code": "DEFAULT_UPDATE", "item_id": "item"}"#;
+        let webhook = Webhook::from_slice(body).unwrap();
+
+        match &webhook {
+            Webhook::Unknown(unknown) => {
+                assert_eq!(unknown.webhook_type, "HOLDINGS");
+                assert_eq!(unknown.body["item_id"], "item");
+            }
+            _ => panic!("unexpected webhook {:?}", webhook),
+        }
+        assert_eq!(
+            serde_json::to_value(&webhook).unwrap(),
+            serde_json::from_slice::<serde_json::Value>(body).unwrap()
+        );
+    }
+
+    #[test]
+    fn malformed_known_webhook_keeps_body() {
+        let body = br#"{"webhook_type": "TRANSACTIONS", "webhook_code": "TRANSACTIONS_REMOVED", "item_id": "item"}"#;
+        let webhook = Webhook::from_slice(body).unwrap();
+
+        match &webhook {
+            Webhook::Malformed(malformed) => {
+                assert_eq!(malformed.webhook_type, "TRANSACTIONS");
+                assert_eq!(malformed.webhook_code, "TRANSACTIONS_REMOVED");
+                assert!(malformed.error.contains("removed_transactions"));
+                assert_eq!(malformed.body["item_id"], "item");
+            }
+            _ => panic!("unexpected webhook {:?}", webhook),
+        }
+        assert_eq!(webhook.webhook_type(), "TRANSACTIONS");
+        assert_eq!(
+            serde_json::to_value(&webhook).unwrap(),
+            serde_json::from_slice::<serde_json::Value>(body).unwrap()
+        );
+    }
+
+    #[test]
+    fn untagged_or_invalid_json_is_an_error() {
+        assert!(Webhook::from_slice(br#"{"item_id": "item"}"#).is_err());
+        assert!(Webhook::from_slice(b"not json").is_err());
+    }
+
+    #[test]
+    fn serializes_with_tags() {
+        let webhook = Webhook::Item(ItemWebhook::WebhookUpdateAcknowledged(
+            WebhookUpdateAcknowledgedWebhook {
+                item_id: "item".into(),
+                new_webhook_url: "https://example.com/webhook".into(),
+                error: None,
+                environment: None,
+            },
+        ));
+
+        let value = serde_json::to_value(&webhook).unwrap();
+        assert_eq!(value["webhook_type"], "ITEM");
+        assert_eq!(value["webhook_code"], "WEBHOOK_UPDATE_ACKNOWLEDGED");
+        let parsed: Webhook = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.webhook_code(), "WEBHOOK_UPDATE_ACKNOWLEDGED");
+    }
+}
@@ -0,0 +1,429 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use p256::ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature, VerifyingKey};
+use p256::EncodedPoint;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::client::{ClientError, Plaid};
+use crate::model::{Jwk, Webhook};
+
+mod dedup;
+mod key_cache;
+#[cfg(feature = "webhook-server")]
+mod server;
+
+pub use dedup::*;
+pub use key_cache::*;
+#[cfg(feature = "webhook-server")]
+pub use server::*;
+
+/// Maximum age of a webhook, based on the JWT `iat` claim, before it is
+/// rejected.
+pub const MAX_WEBHOOK_AGE_SECS: u64 = 5 * 60;
+
+/// How far in the future a webhook's `iat` claim may be, to tolerate clock
+/// skew between Plaid and the receiver.
+pub const MAX_CLOCK_SKEW_SECS: u64 = 60;
+
+/// Error returned when a webhook can't be verified as sent by Plaid.
+#[derive(Error, Debug)]
+pub enum WebhookVerificationError {
+    /// The `Plaid-Verification` header is not a well formed JWT.
+    #[error("malformed verification token: {0}")]
+    MalformedToken(&'static str),
+    /// The JWT is signed with an algorithm other than ES256.
+    #[error("unsupported signing algorithm {0}")]
+    UnsupportedAlgorithm(String),
+    /// The verification key is expired or is not a valid P-256 key.
+    #[error("invalid verification key {0}")]
+    InvalidKey(String),
+    /// The JWT signature doesn't match the verification key.
+    #[error("invalid signature")]
+    InvalidSignature,
+    /// The webhook was issued more than five minutes ago.
+    #[error("webhook issued at {0} is too old")]
+    Expired(u64),
+    /// The webhook claims to be issued more than a minute in the future.
+    #[error("webhook issued at {0} is in the future")]
+    IssuedInFuture(u64),
+    /// The body hash in the JWT doesn't match the webhook body.
+    #[error("webhook body does not match signed hash")]
+    BodyMismatch,
+    /// Error fetching the verification key from Plaid.
+    #[error(transparent)]
+    Client(Box<ClientError>),
+    /// The verified body is not JSON or has no webhook type and code.
+    #[error(transparent)]
+    Parse(#[from] serde_json::Error),
+}
+
+impl From<ClientError> for WebhookVerificationError {
+    fn from(err: ClientError) -> Self {
+        Self::Client(Box::new(err))
+    }
+}
+
+#[derive(Deserialize)]
+struct JwtHeader {
+    alg: String,
+    kid: String,
+}
+
+#[derive(Deserialize)]
+struct JwtClaims {
+    iat: u64,
+    request_body_sha256: String,
+}
+
+/// Verifies that webhooks were sent by Plaid using the JWT in the
+/// `Plaid-Verification` header. Verification keys are fetched with
+/// `create_webhook_verification_key` and cached in a `KeyCache`.
+///
+/// https://plaid.com/docs/api/webhooks/webhook-verification/
+pub struct WebhookVerifier {
+    client: Arc<Plaid>,
+    keys: Arc<KeyCache>,
+}
+
+impl WebhookVerifier {
+    /// Creates a verifier that fetches keys with the given client.
+    pub fn new(client: Arc<Plaid>) -> Self {
+        Self::with_key_cache(client, Arc::new(KeyCache::new()))
+    }
+
+    /// Creates a verifier backed by an existing, possibly shared or
+    /// warm-loaded, key cache.
+    pub fn with_key_cache(client: Arc<Plaid>, keys: Arc<KeyCache>) -> Self {
+        Self { client, keys }
+    }
+
+    /// Returns the verifier's key cache, e.g. to persist it on shutdown.
+    pub fn key_cache(&self) -> &Arc<KeyCache> {
+        &self.keys
+    }
+
+    /// Verifies the webhook and parses the body.
+    pub async fn verify_webhook(
+        &self,
+        verification_header: &str,
+        body: &[u8],
+    ) -> Result<Webhook, WebhookVerificationError> {
+        self.verify(verification_header, body).await?;
+
+        Ok(Webhook::from_slice(body)?)
+    }
+
+    /// Verifies the `Plaid-Verification` header against the raw request body.
+    pub async fn verify(
+        &self,
+        verification_header: &str,
+        body: &[u8],
+    ) -> Result<(), WebhookVerificationError> {
+        let kid = key_id(verification_header)?;
+        // Check the unsigned claims first so requests that can't be valid
+        // never trigger a key fetch from Plaid.
+        let (_, claims, _) = split_token(verification_header)?;
+        let now = now();
+        check_claims(claims, body, now)?;
+        let key = self.keys.key(&self.client, &kid).await?;
+
+        verify_with_key(&key, verification_header, body, now)
+    }
+}
+
+/// Returns the `kid` of the key used to sign the verification JWT, checking
+/// that the token is signed with ES256.
+pub fn key_id(verification_header: &str) -> Result<String, WebhookVerificationError> {
+    let encoded = verification_header
+        .split('.')
+        .next()
+        .ok_or(WebhookVerificationError::MalformedToken("missing header"))?;
+    let header: JwtHeader = decode_json(encoded, "invalid header")?;
+    if header.alg != "ES256" {
+        return Err(WebhookVerificationError::UnsupportedAlgorithm(header.alg));
+    }
+
+    Ok(header.kid)
+}
+
+/// Verifies a webhook against a known key. `now` is the current unix time in
+/// seconds.
+pub fn verify_with_key(
+    key: &Jwk,
+    verification_header: &str,
+    body: &[u8],
+    now: u64,
+) -> Result<(), WebhookVerificationError> {
+    let kid = key_id(verification_header)?;
+    if kid != key.kid {
+        return Err(WebhookVerificationError::InvalidKey(key.kid.clone()));
+    }
+    if key.expired_at.is_some() {
+        return Err(WebhookVerificationError::InvalidKey(key.kid.clone()));
+    }
+
+    let (header, claims, signature) = split_token(verification_header)?;
+
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+        .ok_or(WebhookVerificationError::MalformedToken(
+            "invalid signature",
+        ))?;
+    verifying_key(key)?
+        .verify(format!("{}.{}", header, claims).as_bytes(), &signature)
+        .map_err(|_| WebhookVerificationError::InvalidSignature)?;
+
+    check_claims(claims, body, now)
+}
+
+fn split_token(verification_header: &str) -> Result<(&str, &str, &str), WebhookVerificationError> {
+    let mut parts = verification_header.split('.');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(header), Some(claims), Some(signature), None) => Ok((header, claims, signature)),
+        _ => Err(WebhookVerificationError::MalformedToken(
+            "expected three parts",
+        )),
+    }
+}
+
+/// Checks the webhook's age and body hash against the encoded JWT claims.
+fn check_claims(claims: &str, body: &[u8], now: u64) -> Result<(), WebhookVerificationError> {
+    let claims: JwtClaims = decode_json(claims, "invalid claims")?;
+    if now.saturating_sub(claims.iat) > MAX_WEBHOOK_AGE_SECS {
+        return Err(WebhookVerificationError::Expired(claims.iat));
+    }
+    if claims.iat > now.saturating_add(MAX_CLOCK_SKEW_SECS) {
+        return Err(WebhookVerificationError::IssuedInFuture(claims.iat));
+    }
+
+    let expected = claims.request_body_sha256.to_lowercase();
+    if expected.len() != 64 || !expected.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(WebhookVerificationError::MalformedToken(
+            "invalid body hash",
+        ));
+    }
+    if body_hash(body) != expected {
+        return Err(WebhookVerificationError::BodyMismatch);
+    }
+
+    Ok(())
+}
+
+/// Returns the lowercase hex SHA-256 hash of a webhook body, as signed in the
+/// verification JWT. Plaid signs each retry of a webhook with a new JWT but
+/// the same body, so the hash identifies the event across redeliveries.
+pub fn body_hash(body: &[u8]) -> String {
+    Sha256::digest(body)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn verifying_key(key: &Jwk) -> Result<VerifyingKey, WebhookVerificationError> {
+    let invalid = || WebhookVerificationError::InvalidKey(key.kid.clone());
+    if key.kty != "EC" || key.crv != "P-256" {
+        return Err(invalid());
+    }
+
+    let x = URL_SAFE_NO_PAD.decode(&key.x).map_err(|_| invalid())?;
+    let y = URL_SAFE_NO_PAD.decode(&key.y).map_err(|_| invalid())?;
+    if x.len() != 32 || y.len() != 32 {
+        return Err(invalid());
+    }
+    let point =
+        EncodedPoint::from_affine_coordinates(x.as_slice().into(), y.as_slice().into(), false);
+
+    VerifyingKey::from_encoded_point(&point).map_err(|_| invalid())
+}
+
+fn decode_json<T: serde::de::DeserializeOwned>(
+    encoded: &str,
+    err: &'static str,
+) -> Result<T, WebhookVerificationError> {
+    URL_SAFE_NO_PAD
+        .decode(encoded)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or(WebhookVerificationError::MalformedToken(err))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Temporary file a file at `path` is written to before being renamed over
+/// it, named by appending `.tmp` so it can't collide with a sibling file.
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    tmp.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::Signer;
+    use p256::ecdsa::SigningKey;
+
+    pub(super) const NOW: u64 = 1_700_000_000;
+    pub(super) const BODY: &[u8] = br#"{"webhook_type":"TRANSACTIONS","webhook_code":"SYNC_UPDATES_AVAILABLE","item_id":"item","initial_update_complete":true,"historical_update_complete":true}"#;
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_slice(&[7; 32]).unwrap()
+    }
+
+    pub(super) fn jwk(kid: &str) -> Jwk {
+        let point = signing_key().verifying_key().to_encoded_point(false);
+
+        Jwk {
+            alg: "ES256".into(),
+            crv: "P-256".into(),
+            kid: kid.into(),
+            kty: "EC".into(),
+            key_use: "sig".into(),
+            x: URL_SAFE_NO_PAD.encode(point.x().unwrap()),
+            y: URL_SAFE_NO_PAD.encode(point.y().unwrap()),
+            created_at: NOW - 1000,
+            expired_at: None,
+        }
+    }
+
+    pub(super) fn token(alg: &str, kid: &str, iat: u64, body: &[u8]) -> String {
+        let header = URL_SAFE_NO_PAD
+            .encode(serde_json::json!({ "alg": alg, "kid": kid, "typ": "JWT" }).to_string());
+        let claims = URL_SAFE_NO_PAD.encode(
+            serde_json::json!({ "iat": iat, "request_body_sha256": body_hash(body) }).to_string(),
+        );
+        let message = format!("{}.{}", header, claims);
+        let signature: Signature = signing_key().sign(message.as_bytes());
+
+        format!(
+            "{}.{}",
+            message,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        )
+    }
+
+    #[test]
+    fn accepts_valid_webhook() {
+        let token = token("ES256", "key-1", NOW - 10, BODY);
+
+        assert_eq!(key_id(&token).unwrap(), "key-1");
+        verify_with_key(&jwk("key-1"), &token, BODY, NOW).unwrap();
+    }
+
+    #[test]
+    fn temporary_files_keep_the_full_name() {
+        assert_eq!(tmp_path(Path::new("keys.json")), Path::new("keys.json.tmp"));
+        assert_eq!(tmp_path(Path::new("dir/keys")), Path::new("dir/keys.tmp"));
+    }
+
+    #[test]
+    fn rejects_tampered_body() {
+        let token = token("ES256", "key-1", NOW, BODY);
+        let res = verify_with_key(&jwk("key-1"), &token, b"{}", NOW);
+
+        assert!(matches!(res, Err(WebhookVerificationError::BodyMismatch)));
+    }
+
+    #[test]
+    fn rejects_old_webhook() {
+        let token = token("ES256", "key-1", NOW - MAX_WEBHOOK_AGE_SECS - 1, BODY);
+        let res = verify_with_key(&jwk("key-1"), &token, BODY, NOW);
+
+        assert!(matches!(res, Err(WebhookVerificationError::Expired(_))));
+    }
+
+    #[test]
+    fn rejects_webhook_from_the_future() {
+        let early = token("ES256", "key-1", NOW + MAX_CLOCK_SKEW_SECS + 1, BODY);
+        let res = verify_with_key(&jwk("key-1"), &early, BODY, NOW);
+        assert!(matches!(
+            res,
+            Err(WebhookVerificationError::IssuedInFuture(_))
+        ));
+
+        let skewed = token("ES256", "key-1", NOW + MAX_CLOCK_SKEW_SECS, BODY);
+        assert!(verify_with_key(&jwk("key-1"), &skewed, BODY, NOW).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_signature() {
+        let token = token("ES256", "key-1", NOW, BODY);
+        let mut other = jwk("key-1");
+        let point = SigningKey::from_slice(&[9; 32])
+            .unwrap()
+            .verifying_key()
+            .to_encoded_point(false);
+        other.x = URL_SAFE_NO_PAD.encode(point.x().unwrap());
+        other.y = URL_SAFE_NO_PAD.encode(point.y().unwrap());
+        let res = verify_with_key(&other, &token, BODY, NOW);
+
+        assert!(matches!(
+            res,
+            Err(WebhookVerificationError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn rejects_other_algorithms_and_keys() {
+        let hs256 = token("HS256", "key-1", NOW, BODY);
+        assert!(matches!(
+            key_id(&hs256),
+            Err(WebhookVerificationError::UnsupportedAlgorithm(_))
+        ));
+
+        let token = token("ES256", "key-1", NOW, BODY);
+        let mut expired = jwk("key-1");
+        expired.expired_at = Some(NOW - 1);
+        assert!(matches!(
+            verify_with_key(&expired, &token, BODY, NOW),
+            Err(WebhookVerificationError::InvalidKey(_))
+        ));
+        assert!(matches!(
+            verify_with_key(&jwk("key-2"), &token, BODY, NOW),
+            Err(WebhookVerificationError::InvalidKey(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_tokens() {
+        for token in ["", "a.b", "not base64.b.c"] {
+            assert!(matches!(
+                verify_with_key(&jwk("key-1"), token, BODY, NOW),
+                Err(WebhookVerificationError::MalformedToken(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_claims_before_fetching_keys() {
+        // The client has no credentials and would fail any key fetch, so
+        // these errors can only come from checking the claims first.
+        let verifier = WebhookVerifier::new(Arc::new(crate::client::Builder::new().build()));
+        let now = now();
+
+        let old = token("ES256", "unknown", now - MAX_WEBHOOK_AGE_SECS - 1, BODY);
+        assert!(matches!(
+            verifier.verify(&old, BODY).await,
+            Err(WebhookVerificationError::Expired(_))
+        ));
+        let tampered = token("ES256", "unknown", now, BODY);
+        assert!(matches!(
+            verifier.verify(&tampered, b"{}").await,
+            Err(WebhookVerificationError::BodyMismatch)
+        ));
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+
+use super::MAX_WEBHOOK_AGE_SECS;
+
+/// Default time a processed webhook is remembered for, covering the window in
+/// which Plaid retries a webhook.
+pub const DEFAULT_DEDUP_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Future returned by `WebhookDedupStore` methods.
+pub type DedupFuture<'a, T> = Pin<Box<dyn Future<Output = std::io::Result<T>> + Send + 'a>>;
+
+/// Records which webhooks have been processed so ones Plaid sends more than
+/// once are handled once. Keys are the `body_hash` of verified webhooks,
+/// which stays the same when Plaid retries a webhook with a new JWT.
+///
+/// Methods return boxed futures so stores backed by a database or a cache
+/// such as Redis can be implemented without blocking the runtime.
+pub trait WebhookDedupStore: Send + Sync {
+    /// Records `key` as seen at `now`, a unix timestamp in seconds. Returns
+    /// false if the key was already recorded and the webhook is a duplicate.
+    fn insert<'a>(&'a self, key: &'a str, now: u64) -> DedupFuture<'a, bool>;
+
+    /// Forgets `key` so the webhook is processed again when redelivered, used
+    /// when handling it failed.
+    fn remove<'a>(&'a self, key: &'a str) -> DedupFuture<'a, ()>;
+}
+
+/// A `WebhookDedupStore` kept in memory, suitable for a single process.
+pub struct InMemoryDedupStore {
+    seen: Mutex<HashMap<String, u64>>,
+    retention: u64,
+}
+
+impl Default for InMemoryDedupStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryDedupStore {
+    /// Creates an empty store with the default retention.
+    pub fn new() -> Self {
+        Self {
+            seen: Mutex::new(HashMap::new()),
+            retention: DEFAULT_DEDUP_RETENTION.as_secs(),
+        }
+    }
+
+    /// Sets how long keys are remembered for. Retentions shorter than the
+    /// verification window are raised to `MAX_WEBHOOK_AGE_SECS`.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention.as_secs().max(MAX_WEBHOOK_AGE_SECS);
+        self
+    }
+
+    /// Returns the number of keys currently remembered.
+    pub fn len(&self) -> usize {
+        self.seen.lock().unwrap().len()
+    }
+
+    /// Returns true when no keys are remembered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl WebhookDedupStore for InMemoryDedupStore {
+    fn insert<'a>(&'a self, key: &'a str, now: u64) -> DedupFuture<'a, bool> {
+        let inserted = insert(&mut self.seen.lock().unwrap(), key, now, self.retention);
+        Box::pin(async move { Ok(inserted) })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> DedupFuture<'a, ()> {
+        self.seen.lock().unwrap().remove(key);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// A `WebhookDedupStore` persisted to an append-only log file so processed
+/// webhooks are remembered across restarts. Each change appends a line, the
+/// log is compacted once most of its lines are stale. The file is not meant
+/// to be shared between processes.
+pub struct FileDedupStore {
+    path: PathBuf,
+    seen: Mutex<HashMap<String, u64>>,
+    log: tokio::sync::Mutex<Log>,
+    retention: u64,
+}
+
+struct Log {
+    file: Option<tokio::fs::File>,
+    lines: usize,
+    /// The log ends in a partial line that the next append must terminate.
+    partial: bool,
+}
+
+/// Logs with fewer lines than this are never compacted.
+const MIN_COMPACT_LINES: usize = 1024;
+
+impl FileDedupStore {
+    /// Opens the store at `path` with the default retention, loading any keys
+    /// previously written there. Reads the log synchronously, so call it
+    /// before serving webhooks.
+    pub fn open(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let log = match std::fs::read_to_string(&path) {
+            Ok(log) => log,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let mut seen = HashMap::new();
+        let mut lines = 0;
+        for line in log.lines() {
+            lines += 1;
+            // A crash mid-append can leave a partial last line, skip it.
+            if let Some(key) = line.strip_prefix('-') {
+                seen.remove(key);
+            } else if let Some((now, key)) = line.strip_prefix('+').and_then(|l| l.split_once(' '))
+            {
+                if let Ok(now) = now.parse::<u64>() {
+                    seen.insert(key.to_string(), now);
+                }
+            }
+        }
+
+        Ok(Self {
+            path,
+            seen: Mutex::new(seen),
+            log: tokio::sync::Mutex::new(Log {
+                file: None,
+                lines,
+                partial: !log.is_empty() && !log.ends_with('\n'),
+            }),
+            retention: DEFAULT_DEDUP_RETENTION.as_secs(),
+        })
+    }
+
+    /// Sets how long keys are remembered for. Retentions shorter than the
+    /// verification window are raised to `MAX_WEBHOOK_AGE_SECS`.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention.as_secs().max(MAX_WEBHOOK_AGE_SECS);
+        self
+    }
+
+    async fn append(&self, line: String) -> std::io::Result<()> {
+        let mut log = self.log.lock().await;
+        if log.file.is_none() {
+            let file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)
+                .await?;
+            log.file = Some(file);
+        }
+        let line = if log.partial {
+            format!("\n{}", line)
+        } else {
+            line
+        };
+        let file = log.file.as_mut().expect("log file is open");
+        file.write_all(line.as_bytes()).await?;
+        file.flush().await?;
+        log.partial = false;
+        log.lines += 1;
+
+        let live = self.seen.lock().unwrap().len();
+        if log.lines >= MIN_COMPACT_LINES && log.lines > live * 2 {
+            self.compact(&mut log).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn compact(&self, log: &mut Log) -> std::io::Result<()> {
+        let snapshot: String = self
+            .seen
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(key, now)| format!("+{} {}\n", now, key))
+            .collect();
+        let lines = snapshot.lines().count();
+
+        // Write to a temporary file first so a crash never leaves a truncated
+        // log behind.
+        let tmp = super::tmp_path(&self.path);
+        tokio::fs::write(&tmp, snapshot).await?;
+        log.file = None;
+        tokio::fs::rename(tmp, &self.path).await?;
+        log.lines = lines;
+
+        Ok(())
+    }
+}
+
+impl WebhookDedupStore for FileDedupStore {
+    fn insert<'a>(&'a self, key: &'a str, now: u64) -> DedupFuture<'a, bool> {
+        Box::pin(async move {
+            if !insert(&mut self.seen.lock().unwrap(), key, now, self.retention) {
+                return Ok(false);
+            }
+            if let Err(err) = self.append(format!("+{} {}\n", now, key)).await {
+                self.seen.lock().unwrap().remove(key);
+                return Err(err);
+            }
+
+            Ok(true)
+        })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> DedupFuture<'a, ()> {
+        Box::pin(async move {
+            if self.seen.lock().unwrap().remove(key).is_none() {
+                return Ok(());
+            }
+
+            self.append(format!("-{}\n", key)).await
+        })
+    }
+}
+
+fn insert(seen: &mut HashMap<String, u64>, key: &str, now: u64, retention: u64) -> bool {
+    seen.retain(|_, seen_at| now.saturating_sub(*seen_at) < retention);
+    if seen.contains_key(key) {
+        return false;
+    }
+    seen.insert(key.to_string(), now);
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    #[tokio::test]
+    async fn rejects_duplicates_within_retention() {
+        let store = InMemoryDedupStore::new().with_retention(Duration::from_secs(600));
+
+        assert!(store.insert("a", NOW).await.unwrap());
+        assert!(!store.insert("a", NOW + 599).await.unwrap());
+        assert!(store.insert("b", NOW + 599).await.unwrap());
+        // Expired keys are pruned and the webhook is accepted again.
+        assert!(store.insert("a", NOW + 600).await.unwrap());
+        assert_eq!(store.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn retention_covers_verification_window() {
+        let store = InMemoryDedupStore::new().with_retention(Duration::ZERO);
+
+        assert!(store.insert("a", NOW).await.unwrap());
+        assert!(!store
+            .insert("a", NOW + MAX_WEBHOOK_AGE_SECS - 1)
+            .await
+            .unwrap());
+        assert!(store.insert("a", NOW + MAX_WEBHOOK_AGE_SECS).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn removed_keys_are_processed_again() {
+        let store = InMemoryDedupStore::new();
+
+        assert!(store.insert("a", NOW).await.unwrap());
+        store.remove("a").await.unwrap();
+        assert!(store.insert("a", NOW).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn file_store_persists_across_opens() {
+        let path = std::env::temp_dir().join(format!("rplaid-dedup-{}.log", std::process::id()));
+        let store = FileDedupStore::open(&path).unwrap();
+        assert!(store.insert("a", NOW).await.unwrap());
+        assert!(store.insert("b", NOW).await.unwrap());
+        store.remove("b").await.unwrap();
+        // A partial line left by a crash is ignored.
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, b"+17"))
+            .unwrap();
+
+        let reopened = FileDedupStore::open(&path).unwrap();
+        assert!(!reopened.insert("a", NOW + 1).await.unwrap());
+        assert!(reopened.insert("b", NOW + 1).await.unwrap());
+
+        let reopened = FileDedupStore::open(&path).unwrap();
+        assert!(!reopened.insert("b", NOW + 2).await.unwrap());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn file_store_compacts_stale_lines() {
+        let path =
+            std::env::temp_dir().join(format!("rplaid-dedup-compact-{}.log", std::process::id()));
+        let store = FileDedupStore::open(&path).unwrap();
+        for i in 0..MIN_COMPACT_LINES as u64 {
+            let key = format!("key-{}", i);
+            assert!(store.insert(&key, NOW).await.unwrap());
+            store.remove(&key).await.unwrap();
+        }
+        assert!(store.insert("live", NOW).await.unwrap());
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        assert!(log.lines().count() < MIN_COMPACT_LINES);
+        assert!(log.contains("+1700000000 live\n"));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
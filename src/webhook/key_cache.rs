@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{ClientError, Plaid};
+use crate::model::{ErrorResponse, GetWebhookVerificationKeyRequest, Jwk};
+
+/// Default time after which a cached, unexpired key is refetched to pick up
+/// an `expired_at` set by key rotation.
+pub const DEFAULT_KEY_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Default time a `kid` Plaid rejected is remembered for, so repeated
+/// webhooks signed with an unknown key don't each cause a request to Plaid.
+pub const DEFAULT_UNKNOWN_KEY_TTL: Duration = Duration::from_secs(60);
+
+/// A verification key along with the unix time it was fetched at.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CachedKey {
+    /// The verification key returned by Plaid.
+    pub key: Jwk,
+    /// Unix timestamp, in seconds, the key was fetched at.
+    pub fetched_at: u64,
+}
+
+/// Caches webhook verification keys by `kid` so each key is fetched from
+/// `/webhook_verification_key/get` once rather than on every webhook.
+///
+/// Unknown keys are fetched on demand, concurrent lookups of the same key
+/// share a single request. Unexpired keys are refetched after the refresh
+/// interval so rotated keys pick up their `expired_at`, keys that have
+/// expired are kept as is since expiry is permanent. If a refresh fails
+/// because Plaid can't be reached the cached key keeps being used. Keys Plaid
+/// rejects are remembered for a short time and not requested again. The
+/// cache can be persisted with `save` and warm-loaded with `load` across
+/// restarts.
+pub struct KeyCache {
+    keys: Mutex<HashMap<String, CachedKey>>,
+    unknown: Mutex<HashMap<String, (ErrorResponse, u64)>>,
+    in_flight: Mutex<HashMap<String, FetchLock>>,
+    refresh_interval: Duration,
+    unknown_key_ttl: Duration,
+}
+
+impl Default for KeyCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyCache {
+    /// Creates an empty cache with the default refresh interval.
+    pub fn new() -> Self {
+        Self {
+            keys: Mutex::new(HashMap::new()),
+            unknown: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
+            refresh_interval: DEFAULT_KEY_REFRESH_INTERVAL,
+            unknown_key_ttl: DEFAULT_UNKNOWN_KEY_TTL,
+        }
+    }
+
+    /// Sets how long an unexpired key is used before it is refetched.
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Sets how long a `kid` Plaid rejected is remembered for before it is
+    /// requested again.
+    pub fn with_unknown_key_ttl(mut self, unknown_key_ttl: Duration) -> Self {
+        self.unknown_key_ttl = unknown_key_ttl;
+        self
+    }
+
+    /// Returns a cached key without fetching, regardless of its age.
+    pub fn get(&self, kid: &str) -> Option<Jwk> {
+        self.keys
+            .lock()
+            .unwrap()
+            .get(kid)
+            .map(|cached| cached.key.clone())
+    }
+
+    /// Adds a key to the cache as if it was just fetched.
+    pub fn insert(&self, key: Jwk) {
+        self.keys.lock().unwrap().insert(
+            key.kid.clone(),
+            CachedKey {
+                key,
+                fetched_at: super::now(),
+            },
+        );
+    }
+
+    /// Returns the key for `kid`, fetching it from Plaid if it is unknown or
+    /// due for a refresh.
+    pub async fn key(&self, client: &Plaid, kid: &str) -> Result<Jwk, ClientError> {
+        self.get_or_fetch(kid, || async {
+            Ok(client
+                .create_webhook_verification_key(&GetWebhookVerificationKeyRequest { key_id: kid })
+                .await?
+                .key)
+        })
+        .await
+    }
+
+    fn unknown(&self, kid: &str) -> Option<ErrorResponse> {
+        let unknown = self.unknown.lock().unwrap();
+        let (err, rejected_at) = unknown.get(kid)?;
+        if super::now().saturating_sub(*rejected_at) < self.unknown_key_ttl.as_secs() {
+            return Some(err.clone());
+        }
+
+        None
+    }
+
+    fn remember_unknown(&self, kid: &str, err: ErrorResponse, now: u64) {
+        let ttl = self.unknown_key_ttl.as_secs();
+        let mut unknown = self.unknown.lock().unwrap();
+        unknown.retain(|_, (_, rejected_at)| now.saturating_sub(*rejected_at) < ttl);
+        unknown.insert(kid.to_string(), (err, now));
+    }
+
+    /// Returns the key for `kid`, calling `fetch` if it is unknown or due for
+    /// a refresh. Only one fetch runs per `kid` at a time, concurrent callers
+    /// wait for it and use its result, whether it succeeds or fails.
+    ///
+    /// Keys Plaid rejects are remembered for the unknown key TTL. When a
+    /// refresh fails for another reason, e.g. Plaid is unavailable, the
+    /// previously cached key is returned instead.
+    pub async fn get_or_fetch<F, Fut>(&self, kid: &str, fetch: F) -> Result<Jwk, ClientError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Jwk, ClientError>>,
+    {
+        if let Some(key) = self.fresh(kid) {
+            return Ok(key);
+        }
+        if let Some(err) = self.unknown(kid) {
+            return Err(ClientError::App(err));
+        }
+
+        let lock = self
+            .in_flight
+            .lock()
+            .unwrap()
+            .entry(kid.to_string())
+            .or_default()
+            .clone();
+        let mut failure = lock.lock().await;
+        // Clears the in-flight entry even if the fetch fails or this future is
+        // dropped mid-fetch.
+        let _in_flight = InFlight {
+            in_flight: &self.in_flight,
+            kid,
+            lock: &lock,
+        };
+
+        // Another caller may have fetched the key while we were waiting.
+        if let Some(key) = self.fresh(kid) {
+            return Ok(key);
+        }
+        if let Some(err) = &*failure {
+            return self.stale(kid, err).ok_or_else(|| shared(err));
+        }
+
+        match fetch().await {
+            Ok(key) => {
+                self.keys.lock().unwrap().insert(
+                    kid.to_string(),
+                    CachedKey {
+                        key: key.clone(),
+                        fetched_at: super::now(),
+                    },
+                );
+                Ok(key)
+            }
+            Err(err) => {
+                // Only remember keys Plaid rejected, not transient errors.
+                if let ClientError::App(err) = &err {
+                    self.remember_unknown(kid, err.clone(), super::now());
+                }
+                // Record the failure before releasing the lock so callers
+                // waiting on this fetch share it rather than each retrying.
+                *failure = Some(shared(&err));
+                self.stale(kid, &err).ok_or(err)
+            }
+        }
+    }
+
+    fn fresh(&self, kid: &str) -> Option<Jwk> {
+        let keys = self.keys.lock().unwrap();
+        let cached = keys.get(kid)?;
+        let age = super::now().saturating_sub(cached.fetched_at);
+        if cached.key.expired_at.is_some() || age < self.refresh_interval.as_secs() {
+            return Some(cached.key.clone());
+        }
+
+        None
+    }
+
+    /// Falls back to a key due for a refresh when refetching it failed for a
+    /// reason other than Plaid rejecting the key.
+    fn stale(&self, kid: &str, err: &ClientError) -> Option<Jwk> {
+        if matches!(err, ClientError::App(_)) {
+            return None;
+        }
+
+        self.get(kid)
+    }
+
+    /// Returns every cached key, for persisting the cache.
+    pub fn snapshot(&self) -> Vec<CachedKey> {
+        self.keys.lock().unwrap().values().cloned().collect()
+    }
+
+    /// Adds previously persisted keys, keeping their original fetch times.
+    pub fn restore(&self, keys: impl IntoIterator<Item = CachedKey>) {
+        let mut cache = self.keys.lock().unwrap();
+        for cached in keys {
+            cache.insert(cached.key.kid.clone(), cached);
+        }
+    }
+
+    /// Writes the cached keys to a JSON file.
+    pub async fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_vec(&self.snapshot())?;
+        // Write to a temporary file first so a crash never leaves a truncated
+        // cache behind.
+        let tmp = super::tmp_path(path);
+        tokio::fs::write(&tmp, json).await?;
+        tokio::fs::rename(tmp, path).await
+    }
+
+    /// Restores keys from a JSON file written by `save`.
+    pub async fn load(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let keys: Vec<CachedKey> = serde_json::from_slice(&tokio::fs::read(path).await?)?;
+        self.restore(keys);
+        Ok(())
+    }
+}
+
+/// Per `kid` lock held while fetching, holds the failure of the last fetch
+/// for callers that waited on it.
+type FetchLock = Arc<tokio::sync::Mutex<Option<ClientError>>>;
+
+/// Copies a fetch failure for a caller that waited on the fetch.
+/// `ClientError` isn't `Clone`, so errors other than Plaid's are passed on as
+/// their message.
+fn shared(err: &ClientError) -> ClientError {
+    match err {
+        ClientError::App(err) => ClientError::App(err.clone()),
+        err => ClientError::Io(std::io::Error::other(err.to_string())),
+    }
+}
+
+/// Removes a `kid`'s in-flight lock when dropped, unless another fetch has
+/// since replaced it.
+struct InFlight<'a> {
+    in_flight: &'a Mutex<HashMap<String, FetchLock>>,
+    kid: &'a str,
+    lock: &'a FetchLock,
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight
+            .get(self.kid)
+            .is_some_and(|lock| Arc::ptr_eq(lock, self.lock))
+        {
+            in_flight.remove(self.kid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn jwk(kid: &str, expired_at: Option<u64>) -> Jwk {
+        Jwk {
+            alg: "ES256".into(),
+            crv: "P-256".into(),
+            kid: kid.into(),
+            kty: "EC".into(),
+            key_use: "sig".into(),
+            x: "x".into(),
+            y: "y".into(),
+            created_at: 1_600_000_000,
+            expired_at,
+        }
+    }
+
+    async fn fetch(fetches: &AtomicUsize, kid: &str) -> Result<Jwk, ClientError> {
+        fetches.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        Ok(jwk(kid, None))
+    }
+
+    async fn fail(fetches: &AtomicUsize, err: ClientError) -> Result<Jwk, ClientError> {
+        fetches.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        Err(err)
+    }
+
+    fn unavailable() -> ClientError {
+        ClientError::Io(std::io::Error::other("unavailable"))
+    }
+
+    fn rejected() -> ErrorResponse {
+        ErrorResponse {
+            error_code: Some("INVALID_INPUT".into()),
+            ..ErrorResponse::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_lookups_fetch_once() {
+        let cache = KeyCache::new();
+        let fetches = AtomicUsize::new(0);
+
+        let (a, b, c) = tokio::join!(
+            cache.get_or_fetch("key-1", || fetch(&fetches, "key-1")),
+            cache.get_or_fetch("key-1", || fetch(&fetches, "key-1")),
+            cache.get_or_fetch("key-1", || fetch(&fetches, "key-1")),
+        );
+
+        assert_eq!(a.unwrap().kid, "key-1");
+        assert_eq!(b.unwrap(), c.unwrap());
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn refetches_stale_keys_but_not_expired_ones() {
+        let cache = KeyCache::new().with_refresh_interval(Duration::ZERO);
+        let fetches = AtomicUsize::new(0);
+
+        cache
+            .get_or_fetch("key-1", || fetch(&fetches, "key-1"))
+            .await
+            .unwrap();
+        cache
+            .get_or_fetch("key-1", || fetch(&fetches, "key-1"))
+            .await
+            .unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+        cache.insert(jwk("key-2", Some(1_650_000_000)));
+        let key = cache
+            .get_or_fetch("key-2", || fetch(&fetches, "key-2"))
+            .await
+            .unwrap();
+        assert_eq!(key.expired_at, Some(1_650_000_000));
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_fetches_are_not_cached() {
+        let cache = KeyCache::new();
+
+        let res = cache
+            .get_or_fetch("key-1", || async { Err(unavailable()) })
+            .await;
+        assert!(res.is_err());
+        assert!(cache.get("key-1").is_none());
+        assert!(cache.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn concurrent_lookups_share_failures() {
+        for err in [unavailable, || ClientError::App(rejected())] {
+            let cache = KeyCache::new();
+            let fetches = AtomicUsize::new(0);
+
+            let (a, b, c) = tokio::join!(
+                cache.get_or_fetch("key-1", || fail(&fetches, err())),
+                cache.get_or_fetch("key-1", || fail(&fetches, err())),
+                cache.get_or_fetch("key-1", || fail(&fetches, err())),
+            );
+
+            assert!(a.is_err() && b.is_err() && c.is_err());
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+            assert!(cache.in_flight.lock().unwrap().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_refreshes_use_the_cached_key() {
+        let cache = KeyCache::new().with_refresh_interval(Duration::ZERO);
+        let fetches = AtomicUsize::new(0);
+        cache.insert(jwk("key-1", None));
+
+        let (a, b) = tokio::join!(
+            cache.get_or_fetch("key-1", || fail(&fetches, unavailable())),
+            cache.get_or_fetch("key-1", || fail(&fetches, unavailable())),
+        );
+        assert_eq!(a.unwrap(), jwk("key-1", None));
+        assert_eq!(b.unwrap(), jwk("key-1", None));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        // A key Plaid no longer recognises isn't used.
+        let res = cache
+            .get_or_fetch("key-1", || fail(&fetches, ClientError::App(rejected())))
+            .await;
+        assert!(matches!(res, Err(ClientError::App(_))));
+    }
+
+    #[tokio::test]
+    async fn cancelled_fetches_release_their_lock() {
+        let cache = KeyCache::new();
+        let fetches = AtomicUsize::new(0);
+
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(1),
+            cache.get_or_fetch("key-1", || async {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(jwk("key-1", None))
+            }),
+        )
+        .await;
+        assert!(cancelled.is_err());
+        assert!(cache.in_flight.lock().unwrap().is_empty());
+
+        cache
+            .get_or_fetch("key-1", || fetch(&fetches, "key-1"))
+            .await
+            .unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn rejected_keys_are_not_refetched_until_ttl() {
+        let cache = KeyCache::new();
+        let err = rejected();
+        // The client has no credentials, a fetch would fail with a different
+        // error.
+        let client = crate::client::Builder::new().build();
+
+        cache.remember_unknown("key-1", err.clone(), super::super::now());
+        match cache.key(&client, "key-1").await {
+            Err(ClientError::App(cached)) => assert_eq!(cached, err),
+            res => panic!("unexpected result {:?}", res),
+        }
+
+        cache.remember_unknown("key-2", err, super::super::now() - 60);
+        assert!(cache.unknown("key-2").is_none());
+        assert!(cache.unknown.lock().unwrap().get("key-1").is_some());
+    }
+
+    #[tokio::test]
+    async fn persists_and_warm_loads() {
+        let path =
+            std::env::temp_dir().join(format!("rplaid-key-cache-{}.json", std::process::id()));
+        let cache = KeyCache::new();
+        cache.insert(jwk("key-1", None));
+        cache.save(&path).await.unwrap();
+
+        let warm = KeyCache::new();
+        warm.load(&path).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let fetches = AtomicUsize::new(0);
+        let key = warm
+            .get_or_fetch("key-1", || fetch(&fetches, "key-1"))
+            .await
+            .unwrap();
+        assert_eq!(key, jwk("key-1", None));
+        assert_eq!(fetches.load(Ordering::SeqCst), 0);
+        assert_eq!(warm.snapshot(), cache.snapshot());
+    }
+}
@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use hyper::body::HttpBody;
+use hyper::{Body, Method, Request, Response, StatusCode};
+
+use super::{body_hash, WebhookDedupStore, WebhookVerificationError, WebhookVerifier};
+use crate::model::Webhook;
+
+/// Header Plaid sends the signed verification JWT in.
+pub const VERIFICATION_HEADER: &str = "Plaid-Verification";
+
+/// Webhook bodies larger than this are rejected without being verified.
+pub const MAX_WEBHOOK_BODY_BYTES: usize = 1024 * 1024;
+
+/// Error returned by webhook handlers, causes Plaid to retry the webhook.
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+type Handler = Arc<dyn Fn(Webhook) -> BoxFuture<Result<(), HandlerError>> + Send + Sync>;
+
+/// A hyper `Service` that receives Plaid webhooks, verifies them and
+/// dispatches the parsed `Webhook` to the handler registered for its type and
+/// code.
+///
+/// Responds with:
+/// * `200` once the handler succeeds, when no handler is registered, or when
+///   the same webhook was already processed according to the dedup store.
+/// * `400` when a verified body is not JSON or has no webhook type and code.
+///   Known webhooks with an unexpected payload are dispatched as
+///   `Webhook::Malformed` instead.
+/// * `401` when the verification header is missing or invalid.
+/// * `405` for requests other than POST.
+/// * `413` for bodies over `MAX_WEBHOOK_BODY_BYTES`.
+/// * `500` when the handler fails so Plaid retries the webhook, or the dedup
+///   store can't be updated.
+/// * `503` when the verification key could not be fetched.
+///
+/// ```ignore
+/// let service = WebhookService::builder(verifier)
+///     .on("TRANSACTIONS", "SYNC_UPDATES_AVAILABLE", |webhook| async move {
+///         sync(webhook).await
+///     })
+///     .dedup_store(Arc::new(InMemoryDedupStore::new()))
+///     .build();
+///
+/// hyper::Server::bind(&addr)
+///     .serve(make_service_fn(move |_| {
+///         let service = service.clone();
+///         async move { Ok::<_, Infallible>(service) }
+///     }))
+///     .await?;
+/// ```
+#[derive(Clone)]
+pub struct WebhookService {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    verifier: Arc<WebhookVerifier>,
+    handlers: HashMap<(String, Option<String>), Handler>,
+    fallback: Option<Handler>,
+    dedup: Option<Arc<dyn WebhookDedupStore>>,
+}
+
+/// Registers handlers for a `WebhookService`.
+pub struct WebhookServiceBuilder {
+    verifier: Arc<WebhookVerifier>,
+    handlers: HashMap<(String, Option<String>), Handler>,
+    fallback: Option<Handler>,
+    dedup: Option<Arc<dyn WebhookDedupStore>>,
+}
+
+impl WebhookService {
+    /// Starts building a service that verifies webhooks with `verifier`.
+    pub fn builder(verifier: Arc<WebhookVerifier>) -> WebhookServiceBuilder {
+        WebhookServiceBuilder {
+            verifier,
+            handlers: HashMap::new(),
+            fallback: None,
+            dedup: None,
+        }
+    }
+
+    /// Verifies, parses and dispatches a single webhook request.
+    pub async fn handle(&self, req: Request<Body>) -> Response<Body> {
+        if req.method() != Method::POST {
+            return status(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        let header = match req
+            .headers()
+            .get(VERIFICATION_HEADER)
+            .and_then(|h| h.to_str().ok())
+        {
+            Some(header) => header.to_string(),
+            None => return status(StatusCode::UNAUTHORIZED),
+        };
+        let body = match read_body(req.into_body()).await {
+            Ok(body) => body,
+            Err(code) => return status(code),
+        };
+
+        let webhook = match self.inner.verifier.verify_webhook(&header, &body).await {
+            Ok(webhook) => webhook,
+            Err(WebhookVerificationError::Client(_)) => {
+                return status(StatusCode::SERVICE_UNAVAILABLE)
+            }
+            Err(WebhookVerificationError::Parse(_)) => return status(StatusCode::BAD_REQUEST),
+            Err(_) => return status(StatusCode::UNAUTHORIZED),
+        };
+
+        let handler = match self.inner.handler(&webhook) {
+            Some(handler) => handler,
+            None => return status(StatusCode::OK),
+        };
+
+        let key = body_hash(&body);
+        if let Some(dedup) = &self.inner.dedup {
+            match dedup.insert(&key, super::now()).await {
+                Ok(true) => {}
+                Ok(false) => return status(StatusCode::OK),
+                Err(_) => return status(StatusCode::INTERNAL_SERVER_ERROR),
+            }
+        }
+
+        match handler(webhook).await {
+            Ok(()) => status(StatusCode::OK),
+            Err(_) => {
+                // Forget the webhook so Plaid's retry is handled again.
+                if let Some(dedup) = &self.inner.dedup {
+                    let _ = dedup.remove(&key).await;
+                }
+                status(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        }
+    }
+}
+
+impl Inner {
+    fn handler(&self, webhook: &Webhook) -> Option<&Handler> {
+        let webhook_type = webhook.webhook_type().to_string();
+        let webhook_code = webhook.webhook_code().to_string();
+
+        self.handlers
+            .get(&(webhook_type.clone(), Some(webhook_code)))
+            .or_else(|| self.handlers.get(&(webhook_type, None)))
+            .or(self.fallback.as_ref())
+    }
+}
+
+impl WebhookServiceBuilder {
+    /// Handles webhooks with the given `webhook_type` and `webhook_code`,
+    /// e.g. "TRANSACTIONS" and "SYNC_UPDATES_AVAILABLE".
+    pub fn on<F, Fut, E>(mut self, webhook_type: &str, webhook_code: &str, handler: F) -> Self
+    where
+        F: Fn(Webhook) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Into<HandlerError>,
+    {
+        self.handlers.insert(
+            (webhook_type.to_string(), Some(webhook_code.to_string())),
+            boxed(handler),
+        );
+        self
+    }
+
+    /// Handles webhooks of `webhook_type` that have no handler for their
+    /// specific code.
+    pub fn on_type<F, Fut, E>(mut self, webhook_type: &str, handler: F) -> Self
+    where
+        F: Fn(Webhook) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Into<HandlerError>,
+    {
+        self.handlers
+            .insert((webhook_type.to_string(), None), boxed(handler));
+        self
+    }
+
+    /// Handles webhooks that match no other handler, including `Unknown`
+    /// webhooks. Without a fallback unmatched webhooks are acknowledged and
+    /// dropped.
+    pub fn fallback<F, Fut, E>(mut self, handler: F) -> Self
+    where
+        F: Fn(Webhook) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Into<HandlerError>,
+    {
+        self.fallback = Some(boxed(handler));
+        self
+    }
+
+    /// Records processed webhooks in `store` so redelivered webhooks are
+    /// acknowledged without calling their handler again.
+    pub fn dedup_store(mut self, store: Arc<dyn WebhookDedupStore>) -> Self {
+        self.dedup = Some(store);
+        self
+    }
+
+    /// Consumes the builder returning the service.
+    pub fn build(self) -> WebhookService {
+        WebhookService {
+            inner: Arc::new(Inner {
+                verifier: self.verifier,
+                handlers: self.handlers,
+                fallback: self.fallback,
+                dedup: self.dedup,
+            }),
+        }
+    }
+}
+
+impl hyper::service::Service<Request<Body>> for WebhookService {
+    type Response = Response<Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Result<Response<Body>, Infallible>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let service = self.clone();
+        Box::pin(async move { Ok(service.handle(req).await) })
+    }
+}
+
+fn boxed<F, Fut, E>(handler: F) -> Handler
+where
+    F: Fn(Webhook) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    E: Into<HandlerError>,
+{
+    Arc::new(move |webhook| {
+        let fut = handler(webhook);
+        Box::pin(async move { fut.await.map_err(Into::into) })
+    })
+}
+
+async fn read_body(mut body: Body) -> Result<Vec<u8>, StatusCode> {
+    let mut buf = Vec::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|_| StatusCode::BAD_REQUEST)?;
+        if buf.len() + chunk.len() > MAX_WEBHOOK_BODY_BYTES {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+        buf.extend_from_slice(&chunk);
+    }
+
+    Ok(buf)
+}
+
+fn status(code: StatusCode) -> Response<Body> {
+    let mut res = Response::new(Body::empty());
+    *res.status_mut() = code;
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Builder;
+    use crate::webhook::tests::{jwk, token, BODY};
+    use crate::webhook::{InMemoryDedupStore, KeyCache};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn service(calls: Arc<AtomicUsize>, fail: bool) -> WebhookService {
+        builder(calls, fail).build()
+    }
+
+    fn builder(calls: Arc<AtomicUsize>, fail: bool) -> WebhookServiceBuilder {
+        let keys = KeyCache::new();
+        keys.insert(jwk("key-1"));
+        let verifier =
+            WebhookVerifier::with_key_cache(Arc::new(Builder::new().build()), Arc::new(keys));
+
+        WebhookService::builder(Arc::new(verifier)).on(
+            "TRANSACTIONS",
+            "SYNC_UPDATES_AVAILABLE",
+            move |webhook| {
+                let calls = calls.clone();
+                async move {
+                    assert!(matches!(webhook, Webhook::Transactions(_)));
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    if fail {
+                        return Err("handler failed");
+                    }
+                    Ok(())
+                }
+            },
+        )
+    }
+
+    fn request(token: Option<String>, body: &'static [u8]) -> Request<Body> {
+        let mut req = Request::builder().method(Method::POST).uri("/webhook");
+        if let Some(token) = token {
+            req = req.header(VERIFICATION_HEADER, token);
+        }
+        req.body(Body::from(body)).unwrap()
+    }
+
+    fn valid_token(body: &[u8]) -> String {
+        token("ES256", "key-1", super::super::now(), body)
+    }
+
+    #[tokio::test]
+    async fn dispatches_verified_webhooks() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let res = service(calls.clone(), false)
+            .handle(request(Some(valid_token(BODY)), BODY))
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn handler_failures_are_retried() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let res = service(calls.clone(), true)
+            .handle(request(Some(valid_token(BODY)), BODY))
+            .await;
+
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_unverified_requests() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let service = service(calls.clone(), false);
+
+        let missing = service.handle(request(None, BODY)).await;
+        assert_eq!(missing.status(), StatusCode::UNAUTHORIZED);
+        let tampered = service
+            .handle(request(Some(valid_token(BODY)), b"{}"))
+            .await;
+        assert_eq!(tampered.status(), StatusCode::UNAUTHORIZED);
+        let get = service
+            .handle(Request::get("/webhook").body(Body::empty()).unwrap())
+            .await;
+        assert_eq!(get.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn acknowledges_unhandled_webhooks() {
+        const UNHANDLED: &[u8] =
+            br#"{"webhook_type": "ITEM", "webhook_code": "LOGIN_REPAIRED", "item_id": "item"}"#;
+        let calls = Arc::new(AtomicUsize::new(0));
+        let res = service(calls.clone(), false)
+            .handle(request(Some(valid_token(UNHANDLED)), UNHANDLED))
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn processes_replayed_deliveries_once() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let service = builder(calls.clone(), false)
+            .dedup_store(Arc::new(InMemoryDedupStore::new()))
+            .build();
+        let token = valid_token(BODY);
+
+        let first = service.handle(request(Some(token.clone()), BODY)).await;
+        let replayed = service.handle(request(Some(token), BODY)).await;
+
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(replayed.status(), StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn processes_redeliveries_with_new_tokens_once() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let service = builder(calls.clone(), false)
+            .dedup_store(Arc::new(InMemoryDedupStore::new()))
+            .build();
+        let now = super::super::now();
+
+        // Plaid signs each retry separately, so the tokens differ.
+        for iat in [now - 1, now] {
+            let token = token("ES256", "key-1", iat, BODY);
+            let res = service.handle(request(Some(token), BODY)).await;
+            assert_eq!(res.status(), StatusCode::OK);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_webhooks_are_processed_again() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let service = builder(calls.clone(), true)
+            .dedup_store(Arc::new(InMemoryDedupStore::new()))
+            .build();
+
+        for _ in 0..2 {
+            let res = service.handle(request(Some(valid_token(BODY)), BODY)).await;
+            assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}